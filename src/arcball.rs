@@ -0,0 +1,64 @@
+use crate::Image;
+use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector3};
+use simba::scalar::SubsetOf;
+
+/// Shoemake's arcball orbit induced by displacement on screen.
+///
+/// Implements [`Default`] and can be created with `Arcball::default()`.
+///
+/// Maps positions onto a hemisphere or its silhouette and rotates by twice the angle between
+/// the mapped positions as opposed to the exponential map of [`crate::Orbit`].
+///
+/// Both its methods must be invoked on matching events fired by your 3D graphics library of choice.
+#[derive(Debug, Clone, Default)]
+pub struct Arcball<N: Copy + RealField> {
+	/// Caches previous cursor/finger position mapped onto arcball.
+	vec: Option<Unit<Vector3<N>>>,
+}
+
+impl<N: Copy + RealField> Arcball<N> {
+	/// Computes rotation between previous and current cursor/finger position.
+	///
+	/// Mapping of previous position is cached and has to be discarded on button/finger release via
+	/// [`Self::discard()`]. Current position `pos` is clamped between origin and maximum position
+	/// `max` as screen's width and height. Screen and camera space are defined as for
+	/// [`crate::Orbit::compute()`].
+	///
+	/// Returns `None`:
+	///
+	///   * on first invocation and after [`Self::discard()`] as there is no previous position yet,
+	///   * in the unlikely case that a position event fires twice resulting in zero displacements.
+	pub fn compute(&mut self, pos: &Point2<N>, max: &Point2<N>) -> Option<UnitQuaternion<N>> {
+		// Clamped cursor/finger position from left to right and top to bottom.
+		let pos = Image::clamp_pos_wrt_max(pos, max);
+		// Centered cursor/finger position and its maximum from left to right and bottom to top.
+		let (pos, max) = Image::transform_pos_and_max_wrt_max(&pos, max);
+		// Treat maximum of half the screen's width or height as arcball's radius.
+		let pos = pos.coords / max.x.max(max.y);
+		// Map inner positions onto hemisphere and outer positions onto its silhouette.
+		let len = pos.norm_squared();
+		let vec = if len < N::one() {
+			Unit::new_normalize(pos.push((N::one() - len).sqrt()))
+		} else {
+			Unit::new_normalize(pos.push(N::zero()))
+		};
+		let old = self.vec.replace(vec)?;
+		// Rotation by twice the angle from new to old position for moving camera instead of object.
+		let rot = UnitQuaternion::rotation_between_axis(&vec, &old)?;
+		(rot != UnitQuaternion::identity()).then(|| rot * rot)
+	}
+	/// Discards cached mapping of previous cursor/finger position on button/finger release.
+	pub const fn discard(&mut self) {
+		self.vec = None;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Arcball<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Arcball {
+			vec: self.vec.map(Unit::<Vector3<N>>::cast),
+		}
+	}
+}
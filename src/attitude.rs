@@ -0,0 +1,191 @@
+use crate::{Clamp, Frame, Plane};
+use nalgebra::{Point3, RealField, Unit, UnitQuaternion, Vector3};
+
+/// Angular boundary conditions implementing [`Clamp`].
+///
+/// Implements [`Default`] and can be created with `Attitude::default()` keeping the camera upright.
+///
+/// Limits pitch as elevation of the view direction above the horizon, yaw as azimuth of the view
+/// direction counterclockwise around the up axis from the forward direction, and optionally locks
+/// roll. Pitch and roll are bounded via [`Clamp::up()`] planes whereas yaw is bounded via
+/// vertical [`Clamp::target()`] planes through the eye and [`Clamp::eye()`] planes through the
+/// target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attitude<N: Copy + RealField> {
+	/// Up axis in world space. Default is y-axis.
+	pub up: Unit<Vector3<N>>,
+	/// Forward direction in world space of zero yaw. Default is negative z-axis.
+	pub forward: Unit<Vector3<N>>,
+	/// Minimum pitch angle between `-pi/2` and `pi/2`. Default is `-pi/2`.
+	pub min_pitch: N,
+	/// Maximum pitch angle between `-pi/2` and `pi/2`. Default is `pi/2`.
+	pub max_pitch: N,
+	/// Minimum yaw angle. Default is `-pi`.
+	///
+	/// Yaw is unbounded if the range between minimum and maximum spans a full turn.
+	pub min_yaw: N,
+	/// Maximum yaw angle. Default is `pi`.
+	///
+	/// Yaw is unbounded if the range between minimum and maximum spans a full turn.
+	pub max_yaw: N,
+	/// Whether roll is locked keeping the horizon level. Default is `false`.
+	pub lock_roll: bool,
+	/// Epsilon allowing clamped [`Delta`] to more likely pass revalidation.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	///
+	/// [`Delta`]: crate::Delta
+	/// [AbsDiffEq::default_epsilon()]: approx::AbsDiffEq::default_epsilon()
+	pub hysteresis: N,
+}
+
+impl<N: Copy + RealField> Default for Attitude<N> {
+	fn default() -> Self {
+		Self {
+			up: Vector3::y_axis(),
+			forward: -Vector3::z_axis(),
+			min_pitch: -N::frac_pi_2(),
+			max_pitch: N::frac_pi_2(),
+			min_yaw: -N::pi(),
+			max_yaw: N::pi(),
+			lock_roll: false,
+			hysteresis: N::default_epsilon().sqrt(),
+		}
+	}
+}
+
+impl<N: Copy + RealField> Attitude<N> {
+	/// Horizontal view direction of frame.
+	///
+	/// Returns `None` if the pitch axis of frame is parallel to the up axis.
+	fn heading(&self, frame: &Frame<N>) -> Option<Unit<Vector3<N>>> {
+		let up = frame.yaw_axis();
+		let dir = -frame.roll_axis();
+		let vec = dir.scale(up.dot(&self.up)) - up.scale(dir.dot(&self.up));
+		Unit::try_new(vec, N::default_epsilon())
+	}
+	/// Exceeded pitch plane through origin.
+	fn pitch(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		let heading = self.heading(frame)?;
+		let up = Point3::from(frame.yaw_axis().into_inner());
+		let (sin, cos) = self.max_pitch.sin_cos();
+		let max_plane = Plane::new(
+			Unit::new_normalize(self.up.scale(sin) + heading.scale(cos)),
+			N::zero(),
+		);
+		let (sin, cos) = self.min_pitch.sin_cos();
+		let min_plane = Plane::new(
+			Unit::new_normalize(-self.up.scale(sin) - heading.scale(cos)),
+			N::zero(),
+		);
+		let max_distance = max_plane.distance_from(&up);
+		let min_distance = min_plane.distance_from(&up);
+		(max_distance.max(min_distance) > self.hysteresis).then_some(
+			if max_distance > min_distance {
+				max_plane
+			} else {
+				min_plane
+			},
+		)
+	}
+	/// Normal of exceeded vertical yaw plane toward valid side of horizontal view direction.
+	///
+	/// Yaw is only bounded once pitch is since the horizontal view direction flips beyond the poles.
+	fn yaw(&self, frame: &Frame<N>) -> Option<Unit<Vector3<N>>> {
+		if self.pitch(frame).is_some() {
+			return None;
+		}
+		let two = N::one() + N::one();
+		let half = (self.max_yaw - self.min_yaw) / two;
+		if half >= N::pi() {
+			return None;
+		}
+		let mid = (self.max_yaw + self.min_yaw) / two;
+		let heading = self.heading(frame)?;
+		let forward = Unit::try_new(
+			self.forward.into_inner() - self.up.scale(self.forward.dot(&self.up)),
+			N::default_epsilon(),
+		)?;
+		// Yaw angle relative to center of range.
+		let yaw = self
+			.up
+			.dot(&forward.cross(&heading))
+			.atan2(forward.dot(&heading))
+			- mid;
+		let yaw = yaw.sin().atan2(yaw.cos());
+		// Exceeded limit and orientation of normal toward valid side.
+		let (limit, sign) = if yaw - half > self.hysteresis {
+			(mid + half, -N::one())
+		} else if -half - yaw > self.hysteresis {
+			(mid - half, N::one())
+		} else {
+			return None;
+		};
+		let limit = UnitQuaternion::from_axis_angle(&self.up, limit) * forward;
+		Some(Unit::new_normalize(self.up.cross(&limit).scale(sign)))
+	}
+}
+
+impl<N: Copy + RealField> Clamp<N> for Attitude<N> {
+	/// Find vertical yaw plane through eye exceeded by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.yaw(frame)
+			.map(|normal| Plane::with_point(normal, &frame.eye()))
+	}
+	/// Find vertical yaw plane through target exceeded by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.yaw(frame)
+			.map(|normal| Plane::with_point(-normal, frame.target()))
+	}
+	/// Find pitch or roll plane through origin exceeded by up position.
+	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		if let Some(plane) = self.pitch(frame) {
+			return Some(plane);
+		}
+		let up = Point3::from(frame.yaw_axis().into_inner());
+		if self.lock_roll {
+			let normal = Unit::try_new(self.up.cross(&frame.roll_axis()), N::default_epsilon())?;
+			let distance = normal.dot(&up.coords);
+			if distance.abs() > self.hysteresis {
+				let normal = if distance > N::zero() {
+					-normal
+				} else {
+					normal
+				};
+				return Some(Plane::new(normal, N::zero()));
+			}
+		}
+		None
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Attitude<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Attitude<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De> for Attitude<N> {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
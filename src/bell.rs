@@ -0,0 +1,68 @@
+use crate::Image;
+use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector3};
+use simba::scalar::SubsetOf;
+
+/// Bell's trackball orbit induced by displacement on screen.
+///
+/// Implements [`Default`] and can be created with `Bell::default()`.
+///
+/// Maps positions onto a sphere blending into a hyperbolic sheet outside of its inscribed circle
+/// at 45 degrees as opposed to the exponential map of [`crate::Orbit`].
+///
+/// Both its methods must be invoked on matching events fired by your 3D graphics library of choice.
+#[derive(Debug, Clone, Default)]
+pub struct Bell<N: Copy + RealField> {
+	/// Caches previous cursor/finger position mapped onto trackball.
+	vec: Option<Vector3<N>>,
+}
+
+impl<N: Copy + RealField> Bell<N> {
+	/// Computes rotation between previous and current cursor/finger position.
+	///
+	/// Mapping of previous position is cached and has to be discarded on button/finger release via
+	/// [`Self::discard()`]. Current position `pos` is clamped between origin and maximum position
+	/// `max` as screen's width and height. Screen and camera space are defined as for
+	/// [`crate::Orbit::compute()`].
+	///
+	/// Returns `None`:
+	///
+	///   * on first invocation and after [`Self::discard()`] as there is no previous position yet,
+	///   * in the unlikely case that a position event fires twice resulting in zero displacements.
+	pub fn compute(&mut self, pos: &Point2<N>, max: &Point2<N>) -> Option<UnitQuaternion<N>> {
+		let two = N::one() + N::one();
+		let half = two.recip();
+		// Clamped cursor/finger position from left to right and top to bottom.
+		let pos = Image::clamp_pos_wrt_max(pos, max);
+		// Centered cursor/finger position and its maximum from left to right and bottom to top.
+		let (pos, max) = Image::transform_pos_and_max_wrt_max(&pos, max);
+		// Treat maximum of half the screen's width or height as trackball's radius.
+		let pos = pos.coords / max.x.max(max.y);
+		// Map inner positions onto sphere and outer positions onto hyperbolic sheet.
+		let len = pos.norm_squared();
+		let vec = if len <= half {
+			pos.push((N::one() - len).sqrt())
+		} else {
+			pos.push(half / len.sqrt())
+		};
+		let old = self.vec.replace(vec)?;
+		// Axis from new to old position for moving camera instead of object.
+		let axis = Unit::try_new(vec.cross(&old), N::zero())?;
+		// Angle subtended by chord between both positions.
+		let sin = ((vec - old).norm() / two).min(N::one());
+		Some(UnitQuaternion::from_axis_angle(&axis, sin.asin() * two))
+	}
+	/// Discards cached mapping of previous cursor/finger position on button/finger release.
+	pub const fn discard(&mut self) {
+		self.vec = None;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Bell<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Bell {
+			vec: self.vec.map(Vector3::cast),
+		}
+	}
+}
@@ -68,8 +68,8 @@ impl<N: Copy + RealField> Clamp<N> for Bound<N> {
 			if min_plane.distance_from(&target) > self.hysteresis {
 				return Some(min_plane);
 			}
-			let max_plane = Plane::new(axis, self.max_target[distance]);
-			if max_plane.distance_from(&target) < -self.hysteresis {
+			let max_plane = Plane::new(-axis, -self.max_target[distance]);
+			if max_plane.distance_from(&target) > self.hysteresis {
 				return Some(max_plane);
 			}
 		}
@@ -78,11 +78,14 @@ impl<N: Copy + RealField> Clamp<N> for Bound<N> {
 	/// Find any boundary plane exceeded by eye position.
 	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
 		let distance = frame.distance();
+		let roll_axis = frame.roll_axis();
 		if (self.min_distance - distance) > self.hysteresis {
-			return Some(Plane::new(frame.roll_axis(), self.min_distance));
+			let eye = frame.target() + roll_axis.scale(self.min_distance);
+			return Some(Plane::with_point(roll_axis, &eye));
 		}
 		if (self.max_distance - distance) < -self.hysteresis {
-			return Some(Plane::new(frame.roll_axis(), self.max_distance));
+			let eye = frame.target() + roll_axis.scale(self.max_distance);
+			return Some(Plane::with_point(-roll_axis, &eye));
 		}
 		let eye = self.transform.inverse() * frame.eye();
 		let axes = [Vector3::x_axis(), Vector3::y_axis(), Vector3::z_axis()];
@@ -91,8 +94,8 @@ impl<N: Copy + RealField> Clamp<N> for Bound<N> {
 			if min_plane.distance_from(&eye) > self.hysteresis {
 				return Some(min_plane);
 			}
-			let max_plane = Plane::new(axis, self.max_eye[distance]);
-			if max_plane.distance_from(&eye) < -self.hysteresis {
+			let max_plane = Plane::new(-axis, -self.max_eye[distance]);
+			if max_plane.distance_from(&eye) > self.hysteresis {
 				return Some(max_plane);
 			}
 		}
@@ -112,8 +115,8 @@ impl<N: Copy + RealField> Clamp<N> for Bound<N> {
 			if min_plane.distance_from(&Point3::from(up.into_inner())) > self.hysteresis {
 				return Some(min_plane);
 			}
-			let max_plane = Plane::new(yaw.inverse() * axis, self.max_up[distance]);
-			if max_plane.distance_from(&Point3::from(up.into_inner())) < -self.hysteresis {
+			let max_plane = Plane::new(-(yaw.inverse() * axis), -self.max_up[distance]);
+			if max_plane.distance_from(&Point3::from(up.into_inner())) > self.hysteresis {
 				return Some(max_plane);
 			}
 		}
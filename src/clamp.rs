@@ -1,6 +1,6 @@
 use crate::{Delta, Frame, Plane, Scope};
 use core::fmt::Debug;
-use nalgebra::{Point3, RealField, UnitQuaternion};
+use nalgebra::{Point3, RealField, Unit, UnitQuaternion, Vector3};
 
 /// Clamp wrt abstract boundary conditions of [`Frame`] and [`Scope`].
 ///
@@ -15,7 +15,19 @@ use nalgebra::{Point3, RealField, UnitQuaternion};
 /// one, two, or three loops whenever zero, one, or two boundary conditions intersect (i.e., face,
 /// edge, or corner).
 ///
+/// First person views are supported too. Looking around via [`Delta::First`] is bounded by target
+/// and up planes while preserving the eye position and roll attitude, whereas walking via
+/// [`Delta::Slide`] is bounded by target and eye planes. Let [`Self::target()`] return `None` to
+/// solely bound the eye of a walking camera.
+///
+/// Exceeded boundary planes are oriented with their normals pointing toward the valid side, i.e.,
+/// the positive [`Plane::distance_from()`] of the exceeding position measures how far it exceeds
+/// the boundary condition. Multiple boundary conditions are combined via [`Clamps`] of tuples of
+/// up to four, arrays, and slices of implementors reporting the most exceeded boundary plane, e.g.,
+/// `Clamps::new((bound, terrain))` or `Clamps::new([&'static dyn Clamp<N>; 3])`.
+///
 /// [`Bound`]: crate::Bound
+/// [`Clamps`]: crate::Clamps
 pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 	/// Maximum loops due to maximum possible boundary plane intersections.
 	///
@@ -39,6 +51,9 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>>;
 	/// Exceeded boundary plane for up position in world space.
 	///
+	/// Clamped [`Delta`]s glide the tip of [`Frame::yaw_axis()`] on the unit sphere around the
+	/// origin along the exceeded plane.
+	///
 	/// Must return `None` if up position satisfies all boundary conditions.
 	#[must_use]
 	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>>;
@@ -46,6 +61,93 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 	/// Computes clamped [`Delta`] wrt abstract boundary conditions of [`Frame`] and [`Scope`].
 	///
 	/// Returns `None` if [`Delta`] satisfies all boundary conditions.
+	///
+	/// Scaling toward a position beneath the floor glides the eye along the floor:
+	///
+	/// ```
+	/// use trackball::{
+	///     nalgebra::{Point3, Vector3},
+	///     Bound, Clamp, Delta, Frame, Scope,
+	/// };
+	///
+	/// let floor = Bound {
+	///     min_eye: Point3::new(f64::MIN, 0.0, f64::MIN),
+	///     ..Bound::default()
+	/// };
+	/// let target = Point3::new(0.0, 1.0, 0.0);
+	/// let frame = Frame::look_at(target, &Point3::new(0.0, 1.0, 10.0), &Vector3::y());
+	/// // Scales toward position in camera space beneath the floor.
+	/// let delta = Delta::Scale {
+	///     rat: 0.1,
+	///     pos: Point3::new(0.0, -3.0, 0.0),
+	/// };
+	/// let (delta, _loops) = floor.compute(&frame, &Scope::default(), &delta).unwrap();
+	/// let frame = delta.transform(&frame);
+	/// // Eye lands on the floor while still approaching the position.
+	/// assert!(frame.eye().y.abs() < 1e-6);
+	/// assert!(frame.eye().z < 10.0);
+	/// ```
+	///
+	/// Orbiting diagonally past a pitch limit glides the view direction along the limit:
+	///
+	/// ```
+	/// use core::f64::consts::FRAC_PI_4;
+	/// use trackball::{
+	///     nalgebra::{Point3, UnitQuaternion, Vector3},
+	///     Attitude, Clamp, Delta, Frame, Scope,
+	/// };
+	///
+	/// let attitude = Attitude {
+	///     min_pitch: -FRAC_PI_4,
+	///     max_pitch: FRAC_PI_4,
+	///     ..Attitude::default()
+	/// };
+	/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+	/// // Orbits in camera space by pitching beyond the limit and by yawing.
+	/// let delta = Delta::Orbit {
+	///     rot: UnitQuaternion::from_euler_angles(1.0, 0.5, 0.0),
+	///     pos: Point3::origin(),
+	/// };
+	/// let (delta, _loops) = attitude.compute(&frame, &Scope::default(), &delta).unwrap();
+	/// let dir = -delta.transform(&frame).roll_axis();
+	/// // Pitch of view direction stays inside the limit while its yaw continues.
+	/// assert!(dir.y.asin() <= FRAC_PI_4 + 1e-6);
+	/// assert!(dir.x.abs() > 0.1);
+	/// // Tracks target diagonally beyond the limit.
+	/// let delta = Delta::Track {
+	///     vec: Vector3::new(3.0, 20.0, 0.0),
+	/// };
+	/// let (delta, _loops) = attitude.compute(&frame, &Scope::default(), &delta).unwrap();
+	/// let dir = -delta.transform(&frame).roll_axis();
+	/// assert!(dir.y.asin() <= FRAC_PI_4 + 1e-6);
+	/// assert!(dir.x.abs() > 0.1);
+	/// ```
+	///
+	/// Orbiting around a position off the target glides the target along its boundary:
+	///
+	/// ```
+	/// use trackball::{
+	///     nalgebra::{Point3, UnitQuaternion, Vector3},
+	///     Bound, Clamp, Delta, Frame, Scope,
+	/// };
+	///
+	/// let bound = Bound {
+	///     min_target: Point3::new(-1.0, -1.0, -1.0),
+	///     max_target: Point3::new(1.0, 1.0, 1.0),
+	///     ..Bound::default()
+	/// };
+	/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+	/// // Orbits around position in camera space halfway between target and eye.
+	/// let delta = Delta::Orbit {
+	///     rot: UnitQuaternion::from_euler_angles(0.0, 1.0, 0.0),
+	///     pos: Point3::new(0.0, 0.0, 5.0),
+	/// };
+	/// let (delta, _loops) = bound.compute(&frame, &Scope::default(), &delta).unwrap();
+	/// let target = *delta.transform(&frame).target();
+	/// // Target stays inside of bound while still orbiting.
+	/// assert!(target.coords.amax() <= 1.0 + 1e-6);
+	/// assert!(target.x < -0.5);
+	/// ```
 	#[allow(clippy::too_many_lines)]
 	#[must_use]
 	fn compute(
@@ -57,59 +159,31 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 		match delta {
 			Delta::Frame => None,
 			&Delta::First {
-				pitch: _,
+				pitch,
 				yaw: _,
 				yaw_axis,
 			} => {
+				let old_frame = frame;
 				let eye = frame.eye();
 				let distance = frame.distance();
-				let pitch_axis = frame.pitch_axis();
-				// Old target position in eye space.
-				let old_target = frame.target() - eye;
 				let mut min_delta = *delta;
 				let mut loops = 0;
 				loop {
-					let frame = min_delta.transform(frame);
+					let frame = min_delta.transform(old_frame);
 					let mut bound = false;
 					if let Some(plane) = self.target(&frame) {
 						bound = true;
-						// Center of spherical cap in world space.
-						let center = plane.project_point(&eye);
-						// Height of spherical cap.
-						let height = distance - (center - eye).norm();
-						// Radius of spherical cap.
-						let radius = (height * (distance * (N::one() + N::one()) - height)).sqrt();
-						// New clamped target position in spherical cap space.
-						let new_target = (plane.project_point(frame.target()) - center)
-							.normalize()
-							.scale(radius);
 						// New clamped target position in world space.
-						let new_target = center + new_target;
-						// New clamped target position in eye space.
-						let new_target = new_target - eye;
-
-						// Extract new signed pitch.
-						let pitch_plane = Plane::with_point(pitch_axis, &eye);
-						let old_pitch_target = pitch_plane.project_vector(&old_target);
-						let new_pitch_target = pitch_plane.project_vector(&new_target);
-						let pitch = pitch_plane.angle_between(&old_pitch_target, &new_pitch_target);
-						// Apply signed pitch to old target.
-						let pitch_rot = UnitQuaternion::from_axis_angle(&pitch_axis, pitch);
-						let old_target = pitch_rot * old_target;
-						// Extract left-over signed yaw.
-						let yaw_plane = Plane::with_point(yaw_axis, &eye);
-						let old_yaw_target = yaw_plane.project_vector(&old_target);
-						let new_yaw_target = yaw_plane.project_vector(&new_target);
-						let yaw = yaw_plane.angle_between(&old_yaw_target, &new_yaw_target);
-
-						// FIXME It stutters and seems that roll attitude isn't preserved.
-						#[allow(clippy::no_effect_underscore_binding)]
-						let _min_delta = Delta::First {
-							pitch,
-							yaw,
-							yaw_axis,
-						};
-						min_delta = Delta::Frame;
+						let new_target = spherical_cap(&plane, &eye, distance, frame.target());
+						min_delta = look_around(old_frame, &(new_target - eye), pitch, yaw_axis);
+					}
+					let frame = min_delta.transform(old_frame);
+					if let Some(plane) = self.up(&frame) {
+						bound = true;
+						// Glide up axis along plane by rotating target around eye.
+						let rot = glide_up(&plane, &frame);
+						let new_target = rot * (frame.target() - eye);
+						min_delta = look_around(old_frame, &new_target, pitch, yaw_axis);
 					}
 					if bound {
 						if loops == self.loops() {
@@ -125,7 +199,6 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 			Delta::Track { vec: _ } => {
 				let old_frame = frame;
 				let old_target = frame.target();
-				let old_rot_inverse = frame.view().rotation.inverse();
 				let mut min_delta = *delta;
 				let mut loops = 0;
 				loop {
@@ -134,14 +207,18 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 					if let Some(plane) = self.target(&frame) {
 						bound = true;
 						let new_target = plane.project_point(frame.target());
-						let vec = old_rot_inverse * (new_target - old_target);
+						let vec = new_target - old_target;
 						min_delta = Delta::Track { vec };
 					}
 					let frame = min_delta.transform(old_frame);
-					if let Some(_plane) = self.up(&frame) {
+					if let Some(plane) = self.up(&frame) {
 						bound = true;
-						// TODO Implement gliding.
-						min_delta = Delta::Frame;
+						// Glide up axis along plane by rotating target around preserved eye.
+						let rot = glide_up(&plane, &frame);
+						let eye = frame.eye();
+						let new_target = eye + rot * (frame.target() - eye);
+						let vec = new_target - old_target;
+						min_delta = Delta::Track { vec };
 					}
 					if bound {
 						if loops == self.loops() {
@@ -155,47 +232,53 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 				(min_delta != *delta).then_some((min_delta, loops))
 			}
 			&Delta::Orbit { rot: _, pos } => {
-				if pos != Point3::origin() {
-					return Some((Delta::Frame, 0));
-				}
 				let old_frame = frame;
-				let distance = frame.distance();
-				let target = frame.target();
-				// Rotation from world to camera space for eye in target space.
+				// Rotation from world to camera space.
 				let old_rot_inverse = frame.view().rotation.inverse();
-				// Old eye position in camera space.
-				let old_eye = old_rot_inverse * (frame.eye() - target);
+				// Point to orbit around in world space.
+				let pivot = frame.target() + frame.view().rotation * pos.coords;
+				// Old target and eye position in camera space with origin at pivot.
+				let old_target = -pos.coords;
+				let old_eye = Vector3::z() * frame.distance() - pos.coords;
 				let mut min_delta = *delta;
 				let mut loops = 0;
 				loop {
 					let mut bound = false;
 					let frame = min_delta.transform(old_frame);
+					if pos != Point3::origin() {
+						if let Some(plane) = self.target(&frame) {
+							bound = true;
+							// New clamped target position in world space.
+							let radius = old_target.norm();
+							let new_target = spherical_cap(&plane, &pivot, radius, frame.target());
+							// New clamped target position in camera space.
+							let new_target = old_rot_inverse * (new_target - pivot);
+							// New delta rotation in camera space.
+							let rot = UnitQuaternion::rotation_between(&old_target, &new_target)
+								.unwrap_or_default();
+							min_delta = Delta::Orbit { rot, pos };
+						}
+					}
+					let frame = min_delta.transform(old_frame);
 					if let Some(plane) = self.eye(&frame) {
 						bound = true;
-						// Center of spherical cap in world space.
-						let center = plane.project_point(target);
-						// Height of spherical cap.
-						let height = distance - (center - target).norm();
-						// Radius of spherical cap.
-						let radius = (height * (distance * (N::one() + N::one()) - height)).sqrt();
-						// New clamped eye position in spherical cap space.
-						let new_eye = (plane.project_point(&frame.eye()) - center)
-							.normalize()
-							.scale(radius);
 						// New clamped eye position in world space.
-						let new_eye = center + new_eye;
+						let new_eye = spherical_cap(&plane, &pivot, old_eye.norm(), &frame.eye());
 						// New clamped eye position in camera space.
-						let new_eye = old_rot_inverse * (new_eye - target);
+						let new_eye = old_rot_inverse * (new_eye - pivot);
 						// New delta rotation in camera space.
 						let rot = UnitQuaternion::rotation_between(&old_eye, &new_eye)
 							.unwrap_or_default();
 						min_delta = Delta::Orbit { rot, pos };
 					}
 					let frame = min_delta.transform(old_frame);
-					if let Some(_plane) = self.up(&frame) {
+					if let Some(plane) = self.up(&frame) {
 						bound = true;
-						// TODO Implement gliding.
-						min_delta = Delta::Frame;
+						// Glide up axis along plane by rotating eye in world space.
+						let rot = glide_up(&plane, &frame) * frame.view().rotation;
+						// New delta rotation in camera space.
+						let rot = old_rot_inverse * rot;
+						min_delta = Delta::Orbit { rot, pos };
 					}
 					if bound {
 						if loops == self.loops() {
@@ -242,27 +325,51 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 				}
 				(min_delta != *delta).then_some((min_delta, loops))
 			}
-			&Delta::Scale { mut rat, pos } => {
+			Delta::Scale { rat: _, pos: _ } => {
+				let old_frame = frame;
 				let old_zat = frame.distance();
+				let old_target = frame.target();
+				let old_rot_inverse = frame.view().rotation.inverse();
+				let roll_axis = frame.roll_axis();
 				let mut min_delta = *delta;
 				let mut loops = 0;
 				loop {
-					let frame = min_delta.transform(frame);
+					let frame = min_delta.transform(old_frame);
 					let mut bound = false;
-					if let Some(_plane) = self.eye(&frame) {
+					if let Some(plane) = self.eye(&frame) {
 						bound = true;
-						// TODO Implement gliding.
-						min_delta = Delta::Frame;
+						// Vector from exceeded to clamped eye position in world space.
+						let eye = frame.eye();
+						let vec = plane.project_point(&eye) - eye;
+						// Glide along plane by changing distance wrt component along roll axis.
+						let zat = frame.distance() + roll_axis.dot(&vec);
+						// Glide along plane by sliding target wrt component orthogonal to roll axis.
+						let new_target =
+							frame.target() + (vec - roll_axis.scale(roll_axis.dot(&vec)));
+						let vec = old_rot_inverse * (new_target - old_target);
+						min_delta = scale_or_slide(zat / old_zat, vec);
 					}
-					if scope.scale() {
-						let (znear, _zfar) = scope.clip_planes(N::zero());
-						let min_zat = -znear * (N::one() + N::default_epsilon().sqrt());
-						let new_zat = old_zat * rat;
-						if new_zat < min_zat {
-							bound = true;
-							// TODO Implement gliding.
-							rat = min_zat / old_zat;
-							min_delta = Delta::Scale { rat, pos };
+					let frame = min_delta.transform(old_frame);
+					if let Some(plane) = self.target(&frame) {
+						bound = true;
+						// Vector from exceeded to clamped target position in world space.
+						let target = frame.target();
+						let vec = plane.project_point(target) - target;
+						// Glide along plane preserving component of eye position along roll axis.
+						let zat = frame.distance() - roll_axis.dot(&vec);
+						let vec = old_rot_inverse * (target + vec - old_target);
+						min_delta = scale_or_slide(zat / old_zat, vec);
+					}
+					if let Delta::Scale { rat, pos } = min_delta {
+						if scope.scale() {
+							let (znear, _zfar) = scope.clip_planes(N::zero());
+							let min_zat = -znear * (N::one() + N::default_epsilon().sqrt());
+							let new_zat = old_zat * rat;
+							if new_zat < min_zat {
+								bound = true;
+								let rat = min_zat / old_zat;
+								min_delta = Delta::Scale { rat, pos };
+							}
 						}
 					}
 					if bound {
@@ -279,3 +386,128 @@ pub trait Clamp<N: Copy + RealField>: Send + Sync + Debug + 'static {
 		}
 	}
 }
+
+/// Delegates to referenced boundary conditions, e.g., to combine `&'static dyn Clamp<N>`.
+impl<N: Copy + RealField, C: Clamp<N> + ?Sized> Clamp<N> for &'static C {
+	/// Delegates to referenced boundary conditions.
+	fn loops(&self) -> usize {
+		(**self).loops()
+	}
+	/// Delegates to referenced boundary conditions.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		(**self).target(frame)
+	}
+	/// Delegates to referenced boundary conditions.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		(**self).eye(frame)
+	}
+	/// Delegates to referenced boundary conditions.
+	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		(**self).up(frame)
+	}
+	/// Delegates to referenced boundary conditions.
+	fn compute(
+		&self,
+		frame: &Frame<N>,
+		scope: &Scope<N>,
+		delta: &Delta<N>,
+	) -> Option<(Delta<N>, usize)> {
+		(**self).compute(frame, scope, delta)
+	}
+}
+
+/// Delta scaling by ratio and sliding target by vector in camera space.
+///
+/// Degenerates to [`Delta::Slide`] for a vanishing change of the ratio.
+fn scale_or_slide<N: Copy + RealField>(rat: N, vec: Vector3<N>) -> Delta<N> {
+	let den = N::one() - rat;
+	if den.abs() <= N::default_epsilon().sqrt() {
+		Delta::Slide { vec }
+	} else {
+		// Point in camera space whose distance to eye scales by ratio.
+		Delta::Scale {
+			rat,
+			pos: vec.unscale(den).into(),
+		}
+	}
+}
+
+/// Clamps point onto circle where boundary plane intersects sphere nearest to point.
+///
+/// The circle is the boundary of a spherical cap centered at the projected sphere center.
+fn spherical_cap<N: Copy + RealField>(
+	plane: &Plane<N>,
+	center: &Point3<N>,
+	radius: N,
+	point: &Point3<N>,
+) -> Point3<N> {
+	// Center of spherical cap in world space.
+	let cap = plane.project_point(center);
+	// Height of spherical cap.
+	let height = (radius - (cap - center).norm()).max(N::zero());
+	// Radius of spherical cap.
+	let radius = (height * (radius * (N::one() + N::one()) - height)).sqrt();
+	// Clamped point in spherical cap space.
+	let point = (plane.project_point(point) - cap)
+		.try_normalize(N::zero())
+		.map_or_else(Vector3::zeros, |vec| vec.scale(radius));
+	// Clamped point in world space.
+	cap + point
+}
+
+/// Rotation in world space gliding up axis of frame along exceeded boundary plane.
+fn glide_up<N: Copy + RealField>(plane: &Plane<N>, frame: &Frame<N>) -> UnitQuaternion<N> {
+	let up = frame.yaw_axis();
+	// New clamped up position on unit sphere.
+	let new_up = spherical_cap(plane, &Point3::origin(), N::one(), &up.into_inner().into());
+	UnitQuaternion::rotation_between(&up, &new_up.coords).unwrap_or_default()
+}
+
+/// Pitch and yaw orbiting target of frame around eye toward direction preserving roll attitude.
+///
+/// Out of two possible pitch angles, the one requiring less yaw is chosen as the other one flips
+/// the target over the pole of the yaw axis. On a tie, the one closer to `pitch` is chosen.
+fn look_around<N: Copy + RealField>(
+	frame: &Frame<N>,
+	dir: &Vector3<N>,
+	pitch: N,
+	yaw_axis: Unit<Vector3<N>>,
+) -> Delta<N> {
+	let pitch_axis = frame.pitch_axis();
+	// Old and new unit direction from eye to target.
+	let old_dir = (frame.target() - frame.eye()).normalize();
+	let new_dir = dir.normalize();
+	// Elevation of pitched old direction `a * cos(pitch) + b * sin(pitch)` wrt yaw axis has to
+	// match elevation `c` of new direction as yaw preserves elevation.
+	let a = yaw_axis.dot(&old_dir);
+	let b = yaw_axis.dot(&pitch_axis.cross(&old_dir));
+	let c = yaw_axis.dot(&new_dir);
+	let r = a.hypot(b);
+	let phi = b.atan2(a);
+	let off = (c / r).clamp(-N::one(), N::one()).acos();
+	let wrap = |angle: N| angle.sin().atan2(angle.cos());
+	// Extract left-over signed yaw.
+	let yaw_plane = Plane::new(yaw_axis, N::zero());
+	let yaw = |pitch: N| {
+		let old_dir = UnitQuaternion::from_axis_angle(&pitch_axis, pitch) * old_dir;
+		yaw_plane.angle_between(
+			&yaw_plane.project_vector(&old_dir),
+			&yaw_plane.project_vector(&new_dir),
+		)
+	};
+	let (one, two) = (wrap(phi + off), wrap(phi - off));
+	let (one_yaw, two_yaw) = (yaw(one), yaw(two));
+	let tie = (one_yaw.abs() - two_yaw.abs()).abs() <= N::default_epsilon().sqrt();
+	let (pitch, yaw) = if (tie && (one - pitch).abs() < (two - pitch).abs())
+		|| (!tie && one_yaw.abs() < two_yaw.abs())
+	{
+		(one, one_yaw)
+	} else {
+		(two, two_yaw)
+	};
+	Delta::First {
+		pitch,
+		yaw,
+		yaw_axis,
+	}
+}
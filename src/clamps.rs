@@ -0,0 +1,164 @@
+use crate::{Clamp, Frame, Plane};
+use nalgebra::{Point3, RealField};
+
+/// Composite boundary conditions implementing [`Clamp`].
+///
+/// Combines member boundary conditions `C` of tuples of up to four, arrays, or slices of [`Clamp`]
+/// implementors by reporting the most exceeded boundary plane of all members wrt the largest
+/// positive [`Plane::distance_from()`] and by summing their [`Clamp::loops()`]. Clamped [`Delta`]s
+/// glide along the tighter boundary condition first and are revalidated against all of them.
+///
+/// Members are solely queried for their exceeded boundary planes, i.e., member overrides of
+/// [`Clamp::compute()`] are not honoured as the provided [`Clamp::compute()`] glides along the
+/// boundary planes of all members at once.
+///
+/// ```
+/// use trackball::{
+///     nalgebra::{Point3, Vector3},
+///     Bound, Clamp, Clamps, Delta, Ellipsoid, Frame, Scope, Side,
+/// };
+///
+/// // Target inside of box tighter than sphere along x-axis.
+/// let bound = Bound {
+///     min_target: Point3::new(-3.0, -10.0, -10.0),
+///     ..Bound::default()
+/// };
+/// let sphere = Ellipsoid {
+///     target_side: Side::Inside,
+///     ..Ellipsoid::sphere(Point3::origin(), 5.0)
+/// };
+/// let clamps = Clamps::new((bound, sphere));
+/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+/// // Tracks target beyond box and sphere.
+/// let delta = Delta::Track {
+///     vec: Vector3::new(-10.0, 1.0, 0.0),
+/// };
+/// let (delta, _loops) = clamps.compute(&frame, &Scope::default(), &delta).unwrap();
+/// let target = *delta.transform(&frame).target();
+/// // Target glides along tighter boundary plane of box.
+/// assert!((target - Point3::new(-3.0, 1.0, 0.0)).norm() < 1e-6);
+/// ```
+///
+/// [`Delta`]: crate::Delta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Clamps<C> {
+	/// Member boundary conditions, e.g., a tuple, an array, or a slice.
+	pub clamps: C,
+}
+
+impl<C> Clamps<C> {
+	/// Composite boundary conditions of member boundary conditions.
+	#[must_use]
+	pub const fn new(clamps: C) -> Self {
+		Self { clamps }
+	}
+}
+
+/// Combines boundary conditions of members reporting the most exceeded boundary plane.
+impl<N: Copy + RealField, C: Clamp<N>> Clamp<N> for Clamps<C> {
+	/// Sums loops of all boundary conditions.
+	fn loops(&self) -> usize {
+		self.clamps.loops()
+	}
+	/// Finds most exceeded boundary plane by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.clamps.target(frame)
+	}
+	/// Finds most exceeded boundary plane by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.clamps.eye(frame)
+	}
+	/// Finds most exceeded boundary plane by up position.
+	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.clamps.up(frame)
+	}
+}
+
+/// Combines boundary conditions of tuple elements.
+macro_rules! impl_clamp_for_tuple {
+	($($clamp:ident $index:tt),+) => {
+		/// Combines boundary conditions reporting the most exceeded boundary plane, see [`Clamps`].
+		impl<N: Copy + RealField, $($clamp: Clamp<N>),+> Clamp<N> for ($($clamp,)+) {
+			/// Sums loops of all boundary conditions.
+			fn loops(&self) -> usize {
+				0 $(+ self.$index.loops())+
+			}
+			/// Finds most exceeded boundary plane by target position.
+			fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+				most_exceeded([$(self.$index.target(frame)),+], frame.target())
+			}
+			/// Finds most exceeded boundary plane by eye position.
+			fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+				most_exceeded([$(self.$index.eye(frame)),+], &frame.eye())
+			}
+			/// Finds most exceeded boundary plane by up position.
+			fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+				most_exceeded([$(self.$index.up(frame)),+], &frame.yaw_axis().into_inner().into())
+			}
+		}
+	};
+}
+
+impl_clamp_for_tuple!(A 0, B 1);
+impl_clamp_for_tuple!(A 0, B 1, C 2);
+impl_clamp_for_tuple!(A 0, B 1, C 2, D 3);
+
+/// Combines boundary conditions reporting the most exceeded boundary plane, see [`Clamps`].
+impl<N: Copy + RealField, C: Clamp<N>> Clamp<N> for [C] {
+	/// Sums loops of all boundary conditions.
+	fn loops(&self) -> usize {
+		self.iter().map(Clamp::loops).sum()
+	}
+	/// Finds most exceeded boundary plane by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		most_exceeded(self.iter().map(|clamp| clamp.target(frame)), frame.target())
+	}
+	/// Finds most exceeded boundary plane by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		most_exceeded(self.iter().map(|clamp| clamp.eye(frame)), &frame.eye())
+	}
+	/// Finds most exceeded boundary plane by up position.
+	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		let up = frame.yaw_axis().into_inner().into();
+		most_exceeded(self.iter().map(|clamp| clamp.up(frame)), &up)
+	}
+}
+
+/// Combines boundary conditions reporting the most exceeded boundary plane, see [`Clamps`].
+impl<N: Copy + RealField, C: Clamp<N>, const M: usize> Clamp<N> for [C; M] {
+	/// Sums loops of all boundary conditions.
+	fn loops(&self) -> usize {
+		self.as_slice().loops()
+	}
+	/// Finds most exceeded boundary plane by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.as_slice().target(frame)
+	}
+	/// Finds most exceeded boundary plane by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.as_slice().eye(frame)
+	}
+	/// Finds most exceeded boundary plane by up position.
+	fn up(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.as_slice().up(frame)
+	}
+}
+
+/// Most exceeded boundary plane wrt largest positive distance from `point`.
+///
+/// The distance is signed as boundary plane normals point toward the valid side. Up planes are
+/// measured at the tip of [`Frame::yaw_axis()`] which is the position gliding along them.
+fn most_exceeded<N: Copy + RealField>(
+	planes: impl IntoIterator<Item = Option<Plane<N>>>,
+	point: &Point3<N>,
+) -> Option<Plane<N>> {
+	planes
+		.into_iter()
+		.flatten()
+		.map(|plane| (plane.distance_from(point), plane))
+		.fold(None, |max, (distance, plane)| match max {
+			Some((max_distance, _plane)) if max_distance >= distance => max,
+			_ => Some((distance, plane)),
+		})
+		.map(|(_distance, plane)| plane)
+}
@@ -0,0 +1,122 @@
+use crate::{Clamp, Delta, Frame, Scope, Transition};
+use nalgebra::{RealField, convert};
+use simba::scalar::SubsetOf;
+
+/// Elastic boundary conditions rubber-banding beyond [`Clamp`] while dragging.
+///
+/// Implements [`Default`] and can be created with `Elastic::default()`.
+///
+/// Applies [`Delta`]s to a virtual unclamped frame and to a valid clamped frame nearest to it. The
+/// rendered frame overshoots the valid frame toward the virtual frame by a resistance curve
+/// approaching the resistance length asymptotically. On release, [`Self::discard()`] yields a
+/// [`Transition`] springing back to the valid frame.
+///
+/// Invoke [`Self::compute()`] for each [`Delta`] while dragging and render its returned frame, then
+/// invoke [`Self::discard()`] on button/finger release and run its [`Transition`] to spring back.
+#[derive(Debug, Clone)]
+pub struct Elastic<N: Copy + RealField> {
+	/// Valid and virtual frame while dragging.
+	frames: Option<(Frame<N>, Frame<N>)>,
+	/// Resistance length as ratio of distance between eye and target. Default is `0.25`.
+	res: N,
+}
+
+impl<N: Copy + RealField> Default for Elastic<N> {
+	fn default() -> Self {
+		Self {
+			frames: None,
+			res: convert(0.25),
+		}
+	}
+}
+
+impl<N: Copy + RealField> Elastic<N> {
+	/// Computes frame to render after applying `delta` wrt boundary conditions of `clamp`.
+	///
+	/// Valid and virtual frames are initialized with `frame` on first invocation and after
+	/// [`Self::discard()`] and ignore `frame` otherwise.
+	pub fn compute<C: Clamp<N> + ?Sized>(
+		&mut self,
+		frame: &Frame<N>,
+		scope: &Scope<N>,
+		delta: &Delta<N>,
+		clamp: &C,
+	) -> Frame<N> {
+		let (valid, virt) = self.frames.get_or_insert((*frame, *frame));
+		*virt = delta.transform(virt);
+		if clamp.target(virt).is_none() && clamp.eye(virt).is_none() && clamp.up(virt).is_none() {
+			*valid = *virt;
+		} else {
+			// Glide valid frame unless staying is nearer to virtual frame, e.g., when dragging back.
+			let min_delta = clamp
+				.compute(valid, scope, delta)
+				.map_or(*delta, |(min_delta, _loops)| min_delta);
+			let glide = min_delta.transform(valid);
+			if overshoot(&glide, virt) < overshoot(valid, virt) {
+				*valid = glide;
+			}
+		}
+		self.frame().unwrap_or(*frame)
+	}
+	/// Frame to render overshooting valid frame toward virtual frame by resistance curve.
+	///
+	/// Returns `None` unless dragging.
+	#[must_use]
+	pub fn frame(&self) -> Option<Frame<N>> {
+		let (valid, virt) = self.frames.as_ref()?;
+		let len = overshoot(valid, virt);
+		let res = self.res * valid.distance();
+		Some(if len > N::zero() {
+			valid.lerp_slerp(virt, res / (res + len))
+		} else {
+			*valid
+		})
+	}
+	/// Valid frame nearest to virtual frame.
+	///
+	/// Returns `None` unless dragging.
+	#[must_use]
+	pub fn valid(&self) -> Option<&Frame<N>> {
+		self.frames.as_ref().map(|(valid, _virt)| valid)
+	}
+	/// Discards valid and virtual frame on button/finger release.
+	///
+	/// Returns [`Transition`] of duration `dur` in seconds springing back from overshooting to
+	/// valid frame or `None` if there is no overshoot.
+	pub fn discard(&mut self, dur: N) -> Option<Transition<N>> {
+		let frame = self.frame()?;
+		let (valid, _virt) = self.frames.take()?;
+		(frame != valid).then(|| Transition::new(frame, valid, dur))
+	}
+	/// Resistance length as ratio of distance between eye and target. Default is `0.25`.
+	///
+	/// The overshoot approaches this length asymptotically.
+	#[must_use]
+	pub const fn resistance(&self) -> N {
+		self.res
+	}
+	/// Sets resistance length as ratio of distance between eye and target. Default is `0.25`.
+	///
+	/// Zero disables overshooting.
+	pub const fn set_resistance(&mut self, res: N) {
+		self.res = res;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Elastic<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Elastic {
+			frames: self.frames.map(|(valid, virt)| (valid.cast(), virt.cast())),
+			res: self.res.to_superset(),
+		}
+	}
+}
+
+/// Overshoot length as maximum displacement of target and eye between frames.
+fn overshoot<N: Copy + RealField>(valid: &Frame<N>, virt: &Frame<N>) -> N {
+	let target = (virt.target() - valid.target()).norm();
+	let eye = (virt.eye() - valid.eye()).norm();
+	target.max(eye)
+}
@@ -0,0 +1,180 @@
+use crate::{Clamp, Frame, Plane};
+use nalgebra::{Isometry3, Point3, RealField, Unit, Vector3};
+
+/// Side of boundary surface a position is kept on.
+///
+/// Implements [`Default`] and can be created with `Side::default()` returning `Side::Any`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+	/// Either side, i.e., unbounded (default).
+	#[default]
+	Any,
+	/// Inside of boundary surface.
+	Inside,
+	/// Outside of boundary surface.
+	Outside,
+}
+
+/// Ellipsoidal boundary conditions implementing [`Clamp`].
+///
+/// Implements [`Default`] and can be created with `Ellipsoid::default()` returning an unbounded
+/// unit sphere at the origin.
+///
+/// Keeps target and eye position inside or outside of an ellipsoid, e.g., the eye above the
+/// surface of a planet via [`Side::Outside`]. The exceeded boundary plane is tangent to the
+/// ellipsoid where the exceeding position is radially projected onto its surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipsoid<N: Copy + RealField> {
+	/// Isometry in world space of ellipsoid centered at its origin with its principal axes along
+	/// its coordinate axes. Default is identity.
+	pub transform: Isometry3<N>,
+	/// Positive radii along principal axes. Default splats `N::one()`.
+	pub radii: Vector3<N>,
+	/// Side of ellipsoid the target position is kept on. Default is [`Side::Any`].
+	pub target_side: Side,
+	/// Side of ellipsoid the eye position is kept on. Default is [`Side::Any`].
+	pub eye_side: Side,
+	/// Epsilon allowing clamped [`Delta`] to more likely pass revalidation.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	///
+	/// [`Delta`]: crate::Delta
+	/// [AbsDiffEq::default_epsilon()]: approx::AbsDiffEq::default_epsilon()
+	pub hysteresis: N,
+}
+
+impl<N: Copy + RealField> Default for Ellipsoid<N> {
+	fn default() -> Self {
+		Self {
+			transform: Isometry3::default(),
+			radii: Vector3::repeat(N::one()),
+			target_side: Side::default(),
+			eye_side: Side::default(),
+			hysteresis: N::default_epsilon().sqrt(),
+		}
+	}
+}
+
+impl<N: Copy + RealField> Ellipsoid<N> {
+	/// Unbounded sphere of `radius` at `center`.
+	///
+	/// ```
+	/// use trackball::{nalgebra::Point3, Ellipsoid, Side};
+	///
+	/// // Keeps eye above surface of planet.
+	/// let planet = Ellipsoid {
+	///     eye_side: Side::Outside,
+	///     ..Ellipsoid::sphere(Point3::origin(), 6371.0)
+	/// };
+	/// ```
+	#[must_use]
+	pub fn sphere(center: Point3<N>, radius: N) -> Self {
+		Self {
+			transform: Isometry3::from(center.coords),
+			radii: Vector3::repeat(radius),
+			..Self::default()
+		}
+	}
+	/// Tangent boundary plane in world space if `point` in world space exceeds `side`.
+	///
+	/// The plane normal points toward the valid side.
+	fn exceeded(&self, side: Side, point: &Point3<N>) -> Option<Plane<N>> {
+		let sign = match side {
+			Side::Any => return None,
+			Side::Inside => -N::one(),
+			Side::Outside => N::one(),
+		};
+		let point = self.transform.inverse_transform_point(point);
+		// Direction of point in unit sphere space falling back to smallest radius at center.
+		let dir = point
+			.coords
+			.component_div(&self.radii)
+			.try_normalize(N::zero())
+			.unwrap_or_else(|| Vector3::ith(self.radii.imin(), N::one()));
+		// Point radially projected onto surface.
+		let surface = Point3::from(dir.component_mul(&self.radii));
+		// Surface normal toward valid side.
+		let normal = Unit::new_normalize(dir.component_div(&self.radii) * sign);
+		let plane = Plane::with_point(normal, &surface);
+		(plane.distance_from(&point) > self.hysteresis).then(|| plane.transform_by(&self.transform))
+	}
+}
+
+impl<N: Copy + RealField> Clamp<N> for Ellipsoid<N> {
+	/// Find tangent boundary plane exceeded by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(self.target_side, frame.target())
+	}
+	/// Find tangent boundary plane exceeded by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(self.eye_side, &frame.eye())
+	}
+	/// Up position is unbounded.
+	fn up(&self, _frame: &Frame<N>) -> Option<Plane<N>> {
+		None
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Ellipsoid<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Ellipsoid<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De>
+	for Ellipsoid<N>
+{
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for Side {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized> rkyv::Serialize<Ser> for Side {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized> rkyv::Deserialize<Self, De> for Side {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
@@ -1,5 +1,6 @@
+use crate::Scope;
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
-use nalgebra::{Isometry3, Point3, RealField, Unit, UnitQuaternion, Vector3};
+use nalgebra::{Isometry3, Point2, Point3, RealField, Unit, UnitQuaternion, Vector3, convert};
 use simba::scalar::SubsetOf;
 
 /// Frame wrt camera eye and target.
@@ -143,6 +144,106 @@ impl<N: Copy + RealField> Frame<N> {
 	pub fn roll_axis(&self) -> Unit<Vector3<N>> {
 		self.rot * self.local_roll_axis()
 	}
+	/// Targets center of sphere at distance fitting it into view preserving rotation.
+	///
+	/// Parameters are:
+	///
+	///   * `scope` defining the enclosing viewing frustum,
+	///   * `max` as maximum position in screen space as screen's width and height,
+	///   * `center` and `radius` of the sphere in world space,
+	///   * `margin` as ratio of half the screen's width and height to leave free.
+	///
+	/// The distance between eye and target is floored to the near clip plane distance as set via
+	/// [`Scope::set_clip_planes()`], e.g., for a radius of zero.
+	#[must_use]
+	pub fn fit_sphere(
+		&self,
+		scope: &Scope<N>,
+		max: &Point2<N>,
+		center: Point3<N>,
+		radius: N,
+		margin: N,
+	) -> Self {
+		let (hor, ver) = fit_tangents(scope, max, margin);
+		let tan = hor.min(ver);
+		let zat = if scope.ortho() {
+			radius / tan
+		} else {
+			// Radius divided by sine of half the narrower field of view.
+			radius * (N::one() + tan * tan).sqrt() / tan
+		};
+		Self {
+			pos: center,
+			rot: self.rot,
+			zat: fit_floor(scope, zat),
+		}
+	}
+	/// Targets center of axis-aligned box at distance fitting it into view preserving rotation.
+	///
+	/// Parameters are as in [`Self::fit_sphere()`] except for `min_pos` and `max_pos` as minimum
+	/// and maximum corner of the box in world space. The distance between eye and target is
+	/// floored as in [`Self::fit_sphere()`], e.g., for a degenerate box.
+	#[must_use]
+	pub fn fit_aabb(
+		&self,
+		scope: &Scope<N>,
+		max: &Point2<N>,
+		min_pos: &Point3<N>,
+		max_pos: &Point3<N>,
+		margin: N,
+	) -> Self {
+		let corners = [
+			Point3::new(min_pos.x, min_pos.y, min_pos.z),
+			Point3::new(max_pos.x, min_pos.y, min_pos.z),
+			Point3::new(min_pos.x, max_pos.y, min_pos.z),
+			Point3::new(max_pos.x, max_pos.y, min_pos.z),
+			Point3::new(min_pos.x, min_pos.y, max_pos.z),
+			Point3::new(max_pos.x, min_pos.y, max_pos.z),
+			Point3::new(min_pos.x, max_pos.y, max_pos.z),
+			Point3::new(max_pos.x, max_pos.y, max_pos.z),
+		];
+		self.fit_points(scope, max, &corners, margin)
+			.unwrap_or(*self)
+	}
+	/// Targets center of axis-aligned bounding box of point cloud at distance fitting all points
+	/// into view preserving rotation.
+	///
+	/// Parameters are as in [`Self::fit_sphere()`] except for `points` in world space. The distance
+	/// between eye and target is floored as in [`Self::fit_sphere()`], e.g., for a single point.
+	///
+	/// Returns `None` without points.
+	#[must_use]
+	pub fn fit_points(
+		&self,
+		scope: &Scope<N>,
+		max: &Point2<N>,
+		points: &[Point3<N>],
+		margin: N,
+	) -> Option<Self> {
+		let (first, points) = points.split_first()?;
+		let (min_pos, max_pos) = points.iter().fold((*first, *first), |(min, max), pos| {
+			(min.inf(pos), max.sup(pos))
+		});
+		let pos = min_pos + (max_pos - min_pos) * convert::<_, N>(0.5);
+		let (hor, ver) = fit_tangents(scope, max, margin);
+		let rot = self.rot.inverse();
+		let zat = points.iter().chain([first]).fold(N::zero(), |zat, point| {
+			// Point in camera space with origin at target.
+			let vec = rot * (point - pos);
+			let (x, y) = (vec.x.abs() / hor, vec.y.abs() / ver);
+			if scope.ortho() {
+				zat.max(x).max(y)
+			} else {
+				// Distance of eye from target so that point is on frustum's side planes.
+				zat.max(x + vec.z).max(y + vec.z)
+			}
+		});
+		Some(Self {
+			pos,
+			rot: self.rot,
+			zat: fit_floor(scope, zat),
+		})
+	}
 	/// Attempts to interpolate between two frames using linear interpolation for the translation
 	/// part, and spherical linear interpolation for the rotation part.
 	///
@@ -164,6 +265,25 @@ impl<N: Copy + RealField> Frame<N> {
 			zat: self.zat * (N::one() - t) + other.zat * t,
 		})
 	}
+	/// Interpolates between two frames using linear interpolation for the translation part, and
+	/// spherical linear interpolation for the rotation part.
+	///
+	/// Unlike [`Self::try_lerp_slerp()`], this is well-defined for an angle of 180 degrees between
+	/// both rotations by rotating around the axis of their difference.
+	///
+	/// # Arguments
+	///
+	///   * `self`: The initial frame to interpolate from.
+	///   * `other`: The final frame to interpolate toward.
+	///   * `t`: The interpolation parameter between 0 and 1.
+	#[must_use]
+	pub fn lerp_slerp(&self, other: &Self, t: N) -> Self {
+		Self {
+			pos: self.pos.lerp(&other.pos, t),
+			rot: self.rot * (self.rot.inverse() * other.rot).powf(t),
+			zat: self.zat * (N::one() - t) + other.zat * t,
+		}
+	}
 	/// Renormalizes eye rotation and returns its norm.
 	pub fn renormalize(&mut self) -> N {
 		self.rot.renormalize()
@@ -204,6 +324,23 @@ impl<N: Copy + RealField> Frame<N> {
 	}
 }
 
+/// Floors distance between eye and target to near clip plane distance.
+fn fit_floor<N: Copy + RealField>(scope: &Scope<N>, zat: N) -> N {
+	// Near clip plane distance is negated when measured from target.
+	let (znear, _zfar) = scope.clip_planes(N::zero());
+	zat.max(znear.abs())
+}
+
+/// Tangents of half the horizontal and vertical field of view reduced by margin.
+fn fit_tangents<N: Copy + RealField>(scope: &Scope<N>, max: &Point2<N>, margin: N) -> (N, N) {
+	let two = N::one() + N::one();
+	let fov = scope.fov();
+	let hor = (fov.to_hor(max).into_inner() / two).tan();
+	let ver = (fov.to_ver(max).into_inner() / two).tan();
+	let pad = N::one() - margin;
+	(hor * pad, ver * pad)
+}
+
 impl<N: Copy + RealField + AbsDiffEq> AbsDiffEq for Frame<N>
 where
 	N::Epsilon: Copy,
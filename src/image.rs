@@ -1,10 +1,13 @@
-use crate::{Frame, Scope};
-use nalgebra::{Isometry3, Matrix4, Point2, Point3, RealField, Vector2, Vector3, convert, zero};
+use crate::{Delta, Frame, Scope};
+use nalgebra::{
+	Isometry3, Matrix4, Point2, Point3, RealField, Unit, Vector2, Vector3, convert, zero,
+};
 use simba::scalar::SubsetOf;
 
 /// Image as projection of [`Scope`] wrt [`Frame`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Image<N: Copy + RealField> {
 	/// Current position in screen space of hovering input or pointing device.
 	pos: Point2<N>,
@@ -26,12 +29,22 @@ pub struct Image<N: Copy + RealField> {
 	proj_view_mat: Matrix4<N>,
 	/// Cached inverse of transformation.
 	proj_view_inv: Matrix4<N>,
+	/// Sub-pixel jitter offset in screen space. Default is zero.
+	jitter: Vector2<N>,
+	/// Cached unjittered projection matrix.
+	proj_raw: Matrix4<N>,
+	/// Cached unjittered transformation.
+	proj_view_raw: Matrix4<N>,
+	/// Cached unjittered transformation of previous invocation of [`Self::compute()`].
+	proj_view_prev: Matrix4<N>,
 	/// Whether to compute transformation. Default is `true`.
 	compute_mat: bool,
 	/// Whether to compute inverse transformation. Default is `true`.
 	compute_inv: bool,
 	/// Whether to use passive transformations. Default is `false`.
 	use_passive: bool,
+	/// Whether to use left-handed world and camera space. Default is `false`.
+	use_left_handed: bool,
 }
 
 impl<N: Copy + RealField> Image<N> {
@@ -49,23 +62,33 @@ impl<N: Copy + RealField> Image<N> {
 			proj_mat: zero(),
 			proj_view_mat: zero(),
 			proj_view_inv: zero(),
+			jitter: Vector2::zeros(),
+			proj_raw: zero(),
+			proj_view_raw: zero(),
+			proj_view_prev: zero(),
 			compute_mat: true,
 			compute_inv: true,
 			use_passive: false,
+			use_left_handed: false,
 		};
 		if frame.distance() != N::zero() {
 			image.compute_view(frame);
 			image.compute_projection_and_upp(frame.distance(), scope);
 			image.compute_transformation();
 			image.compute_inverse_transformation();
+			image.proj_view_prev = image.proj_view_raw;
 		}
 		image
 	}
 	/// Recomputes only cached matrices whose parameters have changed, see [`Self::set_compute()`].
 	///
+	/// Keeps the unjittered transformation as [`Self::previous_transformation()`] and hence is to
+	/// be invoked once per rendered frame when computing motion vectors.
+	///
 	/// Returns `Some(true)` on success, `Some(false)` on failure, and `None` with no changes.
 	#[allow(clippy::useless_let_if_seq)]
 	pub fn compute(&mut self, frame: Frame<N>, scope: Scope<N>) -> Option<bool> {
+		self.proj_view_prev = self.proj_view_raw;
 		let mut compute = false;
 		if self.frame != frame {
 			self.compute_view(&frame);
@@ -101,6 +124,24 @@ impl<N: Copy + RealField> Image<N> {
 	pub const fn set_passive(&mut self, use_passive: bool) {
 		self.use_passive = use_passive;
 	}
+	/// Sets whether to use left-handed world and camera space.
+	///
+	/// Mirrors z-axis of cached view and projection matrix such that the camera looks along the
+	/// positive z-axis, e.g., as in Direct3D. The frame remains in right-handed world space, that
+	/// is, positions in left-handed world space correspond to positions of frame with negated
+	/// z-coordinates. Positions of [`Self::ray()`], [`Self::project()`], and [`Self::unproject()`]
+	/// are in world space of frame.
+	///
+	/// Default is `false`.
+	pub fn set_left_handed(&mut self, use_left_handed: bool) {
+		// Let `Self::compute()` recompute view and projection matrix by invalidating cached previous
+		// frame and scope.
+		if self.use_left_handed != use_left_handed {
+			self.frame.set_distance(N::zero());
+			self.scope.set_fov(N::zero());
+		}
+		self.use_left_handed = use_left_handed;
+	}
 	/// Current position in screen space of hovering input or pointing device.
 	#[must_use]
 	pub const fn pos(&self) -> &Point2<N> {
@@ -111,6 +152,8 @@ impl<N: Copy + RealField> Image<N> {
 		self.pos = pos;
 	}
 	/// Maximum position in screen space as screen's width and height.
+	///
+	/// Covers the full virtual screen regardless of [`Scope::tile()`].
 	#[must_use]
 	pub const fn max(&self) -> &Point2<N> {
 		&self.max
@@ -123,6 +166,25 @@ impl<N: Copy + RealField> Image<N> {
 		}
 		self.max = max;
 	}
+	/// Sub-pixel jitter offset in screen space, see [`Self::set_jitter()`].
+	#[must_use]
+	pub const fn jitter(&self) -> &Vector2<N> {
+		&self.jitter
+	}
+	/// Sets sub-pixel jitter offset in screen space, e.g., of [`Jitter::offset()`].
+	///
+	/// Translates [`Self::projection()`] and [`Self::transformation()`] by the offset in pixels of
+	/// the rendered sub-rectangle wrt [`Scope::tile()`] whereas positions in screen space remain
+	/// unjittered. Recomputed with the next invocation of [`Self::compute()`]. Default is zero.
+	///
+	/// [`Jitter::offset()`]: crate::Jitter::offset()
+	pub fn set_jitter(&mut self, jitter: Vector2<N>) {
+		// Let `Self::compute()` recompute projection matrix by invalidating cached previous scope.
+		if self.jitter != jitter {
+			self.scope.set_fov(N::zero());
+		}
+		self.jitter = jitter;
+	}
 	/// Cached unit per pixel on focus plane to scale/project positions/vectors onto focus plane.
 	#[must_use]
 	pub const fn upp(&self) -> N {
@@ -146,16 +208,40 @@ impl<N: Copy + RealField> Image<N> {
 			frame.view()
 		};
 		self.view_mat = self.view_iso.to_homogeneous();
+		if self.use_left_handed {
+			self.view_mat.row_mut(2).neg_mut();
+			self.view_mat.column_mut(2).neg_mut();
+		}
 	}
 	/// Cached projection matrix.
 	#[must_use]
 	pub const fn projection(&self) -> &Matrix4<N> {
 		&self.proj_mat
 	}
-	/// Computes projection matrix and unit per pixel on focus plane.
+	/// Cached unjittered projection matrix.
+	#[must_use]
+	pub const fn unjittered_projection(&self) -> &Matrix4<N> {
+		&self.proj_raw
+	}
+	/// Computes jittered and unjittered projection matrix and unit per pixel on focus plane.
 	pub fn compute_projection_and_upp(&mut self, zat: N, scope: &Scope<N>) {
-		let (mat, upp) = scope.projection_and_upp(zat, &self.max);
+		let (mut mat, upp) = scope.projection_and_upp(zat, &self.max);
+		if self.use_left_handed {
+			mat.column_mut(2).neg_mut();
+		}
 		self.upp = upp;
+		self.proj_raw = mat;
+		// Jitter in normalized device coordinates of sub-rectangle.
+		let (min, max) = scope.tile();
+		let two = N::one() + N::one();
+		let x = self.jitter.x * two / (self.max.x * (max.x - min.x));
+		let y = self.jitter.y * two / (self.max.y * (max.y - min.y));
+		let y = if scope.flipped() { y } else { -y };
+		let w = mat.row(3).into_owned();
+		for (i, jitter) in [(0, x), (1, y)] {
+			let row = mat.row(i) + w.scale(jitter);
+			mat.set_row(i, &row);
+		}
 		self.proj_mat = mat;
 	}
 	/// Cached projection view matrix.
@@ -163,9 +249,22 @@ impl<N: Copy + RealField> Image<N> {
 	pub const fn transformation(&self) -> &Matrix4<N> {
 		&self.proj_view_mat
 	}
-	/// Computes projection view matrix.
+	/// Cached unjittered projection view matrix.
+	#[must_use]
+	pub const fn unjittered_transformation(&self) -> &Matrix4<N> {
+		&self.proj_view_raw
+	}
+	/// Cached unjittered projection view matrix of previous invocation of [`Self::compute()`].
+	///
+	/// Allows to compute motion vectors, e.g., for temporal anti-aliasing.
+	#[must_use]
+	pub const fn previous_transformation(&self) -> &Matrix4<N> {
+		&self.proj_view_prev
+	}
+	/// Computes jittered and unjittered projection view matrix.
 	pub fn compute_transformation(&mut self) {
 		self.proj_view_mat = self.proj_mat * self.view_mat;
+		self.proj_view_raw = self.proj_raw * self.view_mat;
 	}
 	/// Cached inverse projection view matrix.
 	#[must_use]
@@ -214,8 +313,9 @@ impl<N: Copy + RealField> Image<N> {
 	/// Transforms position from screen to camera space and projects it onto focus plane.
 	#[must_use]
 	pub fn project_pos(&self, pos: &Point2<N>) -> Point3<N> {
-		self.transform_pos(pos)
-			.coords
+		// Center of shifted screen in camera space, see [`Scope::set_shift()`].
+		let lens = Self::transform_vec(&self.scope.shift().component_mul(&self.max.coords));
+		(self.transform_pos(pos).coords + lens)
 			.scale(self.upp)
 			.push(N::zero())
 			.into()
@@ -225,6 +325,109 @@ impl<N: Copy + RealField> Image<N> {
 	pub fn project_vec(&self, vec: &Vector2<N>) -> Vector3<N> {
 		Self::transform_vec(vec).scale(self.upp).push(N::zero())
 	}
+	/// Casts ray through position in screen space.
+	///
+	/// Returns origin and direction of ray in world space. The origin is the eye position for
+	/// perspective projection and the position on the plane of the eye orthogonal to the viewing
+	/// direction for orthographic projection.
+	#[must_use]
+	pub fn ray(&self, pos: &Point2<N>) -> (Point3<N>, Unit<Vector3<N>>) {
+		// Position on focus plane in camera space with origin at eye.
+		let pos = self.project_pos(pos) - Vector3::z() * self.frame.distance();
+		let (pos, dir) = if self.scope.ortho() {
+			(Point3::new(pos.x, pos.y, N::zero()), -Vector3::z_axis())
+		} else {
+			(Point3::origin(), Unit::new_normalize(pos.coords))
+		};
+		let view = self.frame.view();
+		(view * pos, view * dir)
+	}
+	/// Projects position in world space to screen space.
+	///
+	/// Returns position in screen space and its depth in normalized device coordinates wrt
+	/// [`Scope::depth()`] and [`Scope::reversed()`] or `None` if the position is behind the eye.
+	#[must_use]
+	pub fn project(&self, pos: &Point3<N>) -> Option<(Point2<N>, N)> {
+		let pos = self.mirror(self.frame.inverse_view() * pos);
+		let pos = self.proj_raw * pos.to_homogeneous();
+		(pos.w > N::zero()).then(|| {
+			let pos = pos.xyz().unscale(pos.w);
+			(self.ndc_to_pos(&pos.xy().into()), pos.z)
+		})
+	}
+	/// Unprojects position in screen space at depth in normalized device coordinates to world
+	/// space.
+	///
+	/// Returns `None` if the projection matrix is not invertible.
+	#[must_use]
+	pub fn unproject(&self, pos: &Point2<N>, depth: N) -> Option<Point3<N>> {
+		self.unproject_pos(pos, depth)
+			.map(|pos| self.frame.view() * (pos - Vector3::z() * self.frame.distance()))
+	}
+	/// Transforms position from screen to camera space and unprojects it at depth in normalized
+	/// device coordinates.
+	///
+	/// Complements [`Self::project_pos()`] for positions off the focus plane, e.g., with depth read
+	/// back from the depth buffer. Returns `None` if the projection matrix is not invertible.
+	#[must_use]
+	pub fn unproject_pos(&self, pos: &Point2<N>, depth: N) -> Option<Point3<N>> {
+		let pos = self.pos_to_ndc(pos).coords.push(depth);
+		let pos = self.proj_raw.try_inverse()?.transform_point(&pos.into());
+		Some(self.mirror(pos) + Vector3::z() * self.frame.distance())
+	}
+	/// Scales by ratio toward position in screen space keeping it under the cursor/finger.
+	///
+	/// Scales around position on focus plane or, with `depth` in normalized device coordinates,
+	/// around the unprojected position, see [`Self::unproject_pos()`]. In orthographic projection
+	/// mode, the position is kept on the focus plane as scaling shrinks the field of view instead
+	/// of the distance to the position.
+	///
+	/// Returns [`Delta::Scale`] with ratio `rat`, e.g., as computed by [`Scale::compute()`].
+	///
+	/// [`Scale::compute()`]: crate::Scale::compute()
+	#[must_use]
+	pub fn scale_toward(&self, rat: N, pos: &Point2<N>, depth: Option<N>) -> Delta<N> {
+		let pos = depth
+			.filter(|_depth| !self.scope.ortho())
+			.and_then(|depth| self.unproject_pos(pos, depth))
+			.unwrap_or_else(|| self.project_pos(pos));
+		Delta::Scale { rat, pos }
+	}
+	/// Mirrors z-axis of position in camera space between right-handed and left-handed camera
+	/// space whether [`Self::set_left_handed()`].
+	fn mirror(&self, pos: Point3<N>) -> Point3<N> {
+		if self.use_left_handed {
+			Point3::new(pos.x, pos.y, -pos.z)
+		} else {
+			pos
+		}
+	}
+	/// Transforms position from screen space to normalized device coordinates wrt
+	/// [`Scope::tile()`] and [`Scope::flipped()`].
+	fn pos_to_ndc(&self, pos: &Point2<N>) -> Point2<N> {
+		let two = N::one() + N::one();
+		let (min, max) = self.scope.tile();
+		// Position as fraction of sub-rectangle.
+		let pos =
+			(pos.coords.component_div(&self.max.coords) - min.coords).component_div(&(max - min));
+		let y = N::one() - pos.y * two;
+		Point2::new(
+			pos.x * two - N::one(),
+			if self.scope.flipped() { -y } else { y },
+		)
+	}
+	/// Transforms position from normalized device coordinates wrt [`Scope::tile()`] and
+	/// [`Scope::flipped()`] to screen space.
+	fn ndc_to_pos(&self, pos: &Point2<N>) -> Point2<N> {
+		let half = convert::<_, N>(0.5);
+		let (min, max) = self.scope.tile();
+		let y = if self.scope.flipped() { -pos.y } else { pos.y };
+		// Position as fraction of sub-rectangle.
+		let pos = Vector2::new((pos.x + N::one()) * half, (N::one() - y) * half);
+		(pos.component_mul(&(max - min)) + min.coords)
+			.component_mul(&self.max.coords)
+			.into()
+	}
 	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
 	#[must_use]
 	pub fn cast<M: Copy + RealField>(self) -> Image<M>
@@ -242,9 +445,14 @@ impl<N: Copy + RealField> Image<N> {
 			proj_mat: self.proj_mat.cast(),
 			proj_view_mat: self.proj_view_mat.cast(),
 			proj_view_inv: self.proj_view_inv.cast(),
+			jitter: self.jitter.cast(),
+			proj_raw: self.proj_raw.cast(),
+			proj_view_raw: self.proj_view_raw.cast(),
+			proj_view_prev: self.proj_view_prev.cast(),
 			compute_mat: self.compute_mat,
 			compute_inv: self.compute_inv,
 			use_passive: self.use_passive,
+			use_left_handed: self.use_left_handed,
 		}
 	}
 }
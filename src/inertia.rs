@@ -0,0 +1,188 @@
+use crate::Delta;
+use core::mem::discriminant;
+use heapless::Deque;
+use nalgebra::{RealField, UnitQuaternion, Vector3, convert};
+use simba::scalar::SubsetOf;
+
+/// Inertia of orbit, slide, and scale decelerating after release.
+///
+/// Implements [`Default`] and can be created with `Inertia::default()`.
+///
+/// Records recent [`Delta::Orbit`], [`Delta::Slide`], and [`Delta::Scale`] with timestamps while
+/// dragging or scrolling, estimates their velocity on release, and yields decaying [`Delta`]s per
+/// tick until the velocity falls below a threshold.
+///
+/// All methods except getters and setters must be invoked on matching events fired by your 3D
+/// graphics library of choice.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inertia<N: Copy + RealField> {
+	/// Recent deltas of the same kind with their timestamps in seconds.
+	log: Deque<(N, Delta<N>), 16>,
+	/// Velocity per second and kind of delta it applies to.
+	///
+	/// Velocity is the rotation vector for [`Delta::Orbit`], the translation vector for
+	/// [`Delta::Slide`], and the logarithmic ratio as x-component for [`Delta::Scale`].
+	vel: Option<(Vector3<N>, Delta<N>)>,
+	/// Friction as exponential decay rate per second. Default is `5.0`.
+	fri: N,
+	/// Time window in seconds of recorded deltas to estimate velocity from. Default is `0.1`.
+	win: N,
+	/// Minimum speed per second below which the velocity vanishes. Default is `1e-3`.
+	min: N,
+}
+
+impl<N: Copy + RealField> Default for Inertia<N> {
+	fn default() -> Self {
+		Self {
+			log: Deque::new(),
+			vel: None,
+			fri: convert(5.0),
+			win: convert(0.1),
+			min: convert(1e-3),
+		}
+	}
+}
+
+impl<N: Copy + RealField> Inertia<N> {
+	/// Records delta applied at timestamp `time` in seconds while button/finger is pressed.
+	///
+	/// Stops any decelerating delta. Deltas other than [`Delta::Orbit`], [`Delta::Slide`], and
+	/// [`Delta::Scale`] or a change of their kind discard previously recorded deltas.
+	pub fn record(&mut self, time: N, delta: &Delta<N>) {
+		self.vel = None;
+		if self
+			.log
+			.back()
+			.is_some_and(|(_time, last)| discriminant(last) != discriminant(delta))
+		{
+			self.log.clear();
+		}
+		if let Delta::Orbit { .. } | Delta::Slide { .. } | Delta::Scale { .. } = delta {
+			if self.log.is_full() {
+				self.log.pop_front();
+			}
+			let _ = self.log.push_back((time, *delta));
+		} else {
+			self.log.clear();
+		}
+	}
+	/// Estimates velocity from recorded deltas on button/finger release at timestamp `time` in
+	/// seconds and discards them.
+	///
+	/// The velocity is estimated from the recorded deltas of the same kind as the last one within
+	/// the time window before `time`. It decays by friction while the button/finger is held still
+	/// between the last recorded delta and its release.
+	///
+	/// Returns `true` if there is a velocity to decelerate.
+	pub fn discard(&mut self, time: N) -> bool {
+		let vel = self.log.back().and_then(|&(last_time, last)| {
+			let mut log = self.log.iter().filter(|&&(then, delta)| {
+				time - then <= self.win && discriminant(&delta) == discriminant(&last)
+			});
+			let &(first, _delta) = log.next()?;
+			let vec = log.fold(Vector3::zeros(), |vec, &(_time, delta)| {
+				vec + match delta {
+					Delta::Orbit { rot, pos: _ } => rot.scaled_axis(),
+					Delta::Slide { vec } => vec,
+					Delta::Scale { rat, pos: _ } => Vector3::x() * rat.ln(),
+					_ => Vector3::zeros(),
+				}
+			});
+			// Duration of recorded deltas following the first one.
+			let dur = last_time - first;
+			// Exponential decay of velocity while held still before release.
+			let dec = (-self.fri * (time - last_time)).exp();
+			(dur > N::zero())
+				.then(|| vec.scale(dec / dur))
+				.filter(|vel| vel.norm() > self.min)
+				.map(|vel| (vel, last))
+		});
+		self.log.clear();
+		self.vel = vel;
+		self.vel.is_some()
+	}
+	/// Computes decelerated delta for tick of duration `dt` in seconds.
+	///
+	/// Returns `None` if there is no velocity to decelerate.
+	pub fn compute(&mut self, dt: N) -> Option<Delta<N>> {
+		let (vel, kind) = self.vel?;
+		// Exponential decay of velocity over tick.
+		let dec = (-self.fri * dt).exp();
+		// Integral of decaying velocity over tick.
+		let vec = if self.fri > N::zero() {
+			vel * ((N::one() - dec) / self.fri)
+		} else {
+			vel * dt
+		};
+		let vel = vel * dec;
+		self.vel = (vel.norm() > self.min).then_some((vel, kind));
+		Some(match kind {
+			Delta::Orbit { rot: _, pos } => Delta::Orbit {
+				rot: UnitQuaternion::from_scaled_axis(vec),
+				pos,
+			},
+			Delta::Slide { vec: _ } => Delta::Slide { vec },
+			Delta::Scale { rat: _, pos } => Delta::Scale {
+				rat: vec.x.exp(),
+				pos,
+			},
+			_ => Delta::Frame,
+		})
+	}
+	/// Stops decelerating delta, e.g., on button/finger press.
+	pub fn stop(&mut self) {
+		self.log.clear();
+		self.vel = None;
+	}
+	/// Whether there is a velocity to decelerate.
+	#[must_use]
+	pub const fn enabled(&self) -> bool {
+		self.vel.is_some()
+	}
+	/// Friction as exponential decay rate per second. Default is `5.0`.
+	#[must_use]
+	pub const fn friction(&self) -> N {
+		self.fri
+	}
+	/// Sets friction as exponential decay rate per second. Default is `5.0`.
+	pub const fn set_friction(&mut self, fri: N) {
+		self.fri = fri;
+	}
+	/// Time window in seconds of recorded deltas to estimate velocity from. Default is `0.1`.
+	#[must_use]
+	pub const fn window(&self) -> N {
+		self.win
+	}
+	/// Sets time window in seconds of recorded deltas to estimate velocity from. Default is `0.1`.
+	pub const fn set_window(&mut self, win: N) {
+		self.win = win;
+	}
+	/// Minimum speed per second below which the velocity vanishes. Default is `1e-3`.
+	#[must_use]
+	pub const fn threshold(&self) -> N {
+		self.min
+	}
+	/// Sets minimum speed per second below which the velocity vanishes. Default is `1e-3`.
+	pub const fn set_threshold(&mut self, min: N) {
+		self.min = min;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Inertia<M>
+	where
+		N: SubsetOf<M>,
+	{
+		let mut log = Deque::new();
+		for (time, delta) in self.log {
+			let _ = log.push_back((time.to_superset(), delta.cast()));
+		}
+		Inertia {
+			log,
+			vel: self.vel.map(|(vel, kind)| (vel.cast(), kind.cast())),
+			fri: self.fri.to_superset(),
+			win: self.win.to_superset(),
+			min: self.min.to_superset(),
+		}
+	}
+}
@@ -0,0 +1,100 @@
+use nalgebra::{RealField, Vector2, convert};
+
+/// Low-discrepancy sequence of sub-pixel jitter offsets, e.g., for temporal anti-aliasing.
+///
+/// Implements [`Default`] and can be created with `Jitter::default()` returning `Jitter::Halton`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Jitter {
+	/// Halton sequence of bases 2 and 3 (default).
+	#[default]
+	Halton,
+	/// R2 sequence of the plastic number as generalized golden ratio.
+	R2,
+}
+
+impl Jitter {
+	/// Sub-pixel jitter offset in screen space between `-0.5` and `0.5` pixels of sample `index`.
+	///
+	/// ```
+	/// use nalgebra::{Point2, Point3, Vector3};
+	/// use trackball::{Frame, Image, Jitter, Scope};
+	///
+	/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 5.0), &Vector3::y());
+	/// let scope = Scope::default();
+	/// let mut image = Image::new(&frame, &scope, Point2::new(800.0, 600.0));
+	/// // Cycles through 8 samples, one per rendered frame.
+	/// for index in 0..8 {
+	///     image.set_jitter(Jitter::Halton.offset(index % 8));
+	///     image.compute(frame, scope);
+	///     // Jittered projection view matrix for rendering.
+	///     let mat = image.transformation();
+	///     // Unjittered projection view matrices of current and previous frame for motion vectors.
+	///     let (new, old) = (image.unjittered_transformation(), image.previous_transformation());
+	/// }
+	/// ```
+	#[must_use]
+	pub fn offset<N: Copy + RealField>(self, index: u32) -> Vector2<N> {
+		let half = convert::<_, N>(0.5);
+		match self {
+			// Skips origin of sequence.
+			Self::Halton => Vector2::new(
+				halton(index.wrapping_add(1), 2),
+				halton(index.wrapping_add(1), 3),
+			),
+			Self::R2 => {
+				// Plastic number as unique real root of `x^3 = x + 1`.
+				let phi = convert::<_, N>(1.324_717_957_244_746);
+				let index = convert::<_, N>(f64::from(index));
+				Vector2::new(
+					(half + index / phi).fract(),
+					(half + index / (phi * phi)).fract(),
+				)
+			}
+		}
+		.add_scalar(-half)
+	}
+}
+
+/// Radical inverse of `index` in `base` as element of Halton sequence between `0` and `1`.
+fn halton<N: Copy + RealField>(mut index: u32, base: u32) -> N {
+	let base_n = convert::<_, N>(f64::from(base));
+	let mut scale = N::one();
+	let mut value = N::zero();
+	while index > 0 {
+		scale /= base_n;
+		value += scale * convert(f64::from(index % base));
+		index /= base;
+	}
+	value
+}
+
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for Jitter {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized> rkyv::Serialize<Ser> for Jitter {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized> rkyv::Deserialize<Self, De> for Jitter {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
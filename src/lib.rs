@@ -16,7 +16,12 @@
 //!   * Common trackball operations split into several operation handlers.
 //!   * Coherent and intuitive orbiting via the exponential map, see [`Orbit`] operation handler.
 //!   * Identical C11 implementation for [`Orbit`] operation handler behind `cc` feature gate.
+//!   * Optional rim roll zone of [`Orbit`] operation handler smoothly blending orbit into roll.
 //!   * Coherent [`First`] person view aka free look or mouse look wrt [`Orbit`] operation handler.
+//!   * [`Turntable`] orbit operation handler yawing around fixed up axis without introducing roll.
+//!   * Classic [`Arcball`], [`Bell`], and two-axis [`Valuator`] orbit operation handlers for
+//!     comparison with [`Orbit`] operation handler.
+//!   * [`Roll`] operation handler rolling around screen's center or pivot for single pointers.
 //!   * Observer [`Frame`] with [`Frame::slide()`], [`Frame::orbit()`], [`Frame::scale()`]
 //!     operations in world space and their local complements in camera space and with orbit and
 //!     slide operations around arbitrary points in either world or camera space.
@@ -25,10 +30,27 @@
 //!     boundary [`Plane`], [`Delta`] is changed in such a way that the clamped movement glides
 //!     along the plane.
 //!   * [`Bound`] implementing [`Clamp`] providing customizable orthogonal boundary conditions.
+//!   * [`Ellipsoid`] implementing [`Clamp`] keeping target and eye inside or outside of a sphere
+//!     or an ellipsoid.
+//!   * [`Polytope`] implementing [`Clamp`] bounding target and eye by arbitrary half-spaces.
+//!   * [`Terrain`] implementing [`Clamp`] keeping target and eye above a [`Height`] function.
+//!   * [`Attitude`] implementing [`Clamp`] limiting pitch, yaw, and roll angles.
+//!   * [`Clamps`] combining boundary conditions of tuples, arrays, and slices of [`Clamp`]s.
+//!   * [`Elastic`] boundary conditions rubber-banding beyond [`Clamp`] and springing back.
 //!   * Object inspection mode scaling clip plane distances by measuring from target instead of eye.
 //!   * Scale-preserving transitioning between orthographic and perspective projection mode.
+//!   * Reversed-Z and infinite far plane projections with [`Depth`] range of OpenGL or Vulkan.
+//!   * Clip space conventions of Vulkan with flipped y-axis and of left-handed world space.
+//!   * Asymmetric frustums covering sub-rectangles of the screen or shifting the lens.
+//!   * [`Stereo`] camera pair of left and right eye [`Image`] with parallel, toe-in, or
+//!     off-axis [`Rig`].
+//!   * Sub-pixel [`Jitter`] of projections and previous transformations for temporal
+//!     anti-aliasing.
 //!   * Converting between [`Fixed`] quantities wrt to field of view, see [`Scope::set_fov()`].
 //!   * Time-free [`Touch`] gesture recognition for slide, orbit, scale, and focus operations.
+//!   * Decelerating [`Inertia`] of orbit, slide, and scale operations after release.
+//!   * Time-based [`Smooth`] transition of observer [`Frame`] damping jittery input.
+//!   * Animated [`Transition`] between frames and scopes with [`Ease`] curves and fly-to zoom.
 //!
 //! # Optional Features
 //!
@@ -85,30 +107,62 @@
 pub use approx;
 pub use nalgebra;
 
+mod arcball;
+mod attitude;
+mod bell;
 mod bound;
 mod clamp;
+mod clamps;
 mod delta;
+mod elastic;
+mod ellipsoid;
 mod first;
 mod fixed;
 mod frame;
 mod image;
+mod inertia;
+mod jitter;
 mod orbit;
 mod plane;
+mod polytope;
+mod roll;
 mod scale;
 mod scope;
 mod slide;
+mod smooth;
+mod stereo;
+mod terrain;
 mod touch;
+mod transition;
+mod turntable;
+mod valuator;
 
+pub use arcball::*;
+pub use attitude::*;
+pub use bell::*;
 pub use bound::*;
 pub use clamp::*;
+pub use clamps::*;
 pub use delta::*;
+pub use elastic::*;
+pub use ellipsoid::*;
 pub use first::*;
 pub use fixed::*;
 pub use frame::*;
 pub use image::*;
+pub use inertia::*;
+pub use jitter::*;
 pub use orbit::*;
 pub use plane::*;
+pub use polytope::*;
+pub use roll::*;
 pub use scale::*;
 pub use scope::*;
 pub use slide::*;
+pub use smooth::*;
+pub use stereo::*;
+pub use terrain::*;
 pub use touch::*;
+pub use transition::*;
+pub use turntable::*;
+pub use valuator::*;
@@ -1,4 +1,4 @@
-use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector3};
+use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector3, convert};
 use simba::scalar::SubsetOf;
 
 #[cfg(not(feature = "cc"))]
@@ -13,6 +13,8 @@ use crate::Image;
 pub struct Orbit<N: Copy + RealField> {
 	/// Caches normalization of previous cursor/finger position.
 	vec: Option<(Unit<Vector3<N>>, N)>,
+	/// Optional inner and outer radius fraction of rim roll zone. Default is `None`.
+	rim: Option<(N, N)>,
 }
 
 #[cfg(not(feature = "cc"))]
@@ -75,7 +77,10 @@ impl<N: Copy + RealField> Orbit<N> {
 		// displacement vector which in turn spans rotation plane together with exponential map.
 		let vec = (img * arg.tr_mul(&ray.into_inner())).cross(&exp);
 		// Angle of rotation is displacement length divided by radius.
-		Unit::try_new(vec, N::zero()).map(|ray| UnitQuaternion::from_axis_angle(&ray, len / max))
+		let rot = Unit::try_new(vec, N::zero())
+			.map(|ray| UnitQuaternion::from_axis_angle(&ray, len / max))?;
+		// Blend with roll in rim roll zone.
+		Some(rim(self.rim, (pos, off), self.vec?, max, rot))
 	}
 	/// Discards cached normalization of previous cursor/finger position on button/finger release.
 	pub const fn discard(&mut self) {
@@ -89,6 +94,9 @@ impl<N: Copy + RealField> Orbit<N> {
 	{
 		Orbit {
 			vec: self.vec.map(|(ray, len)| (ray.cast(), len.to_superset())),
+			rim: self
+				.rim
+				.map(|(inner, outer)| (inner.to_superset(), outer.to_superset())),
 		}
 	}
 }
@@ -134,9 +142,12 @@ impl Orbit<f32> {
 				max.coords.as_ptr(),
 			);
 		}
-		self.vec = Some((Unit::new_unchecked(old.xyz()), old.w));
+		let new = (Unit::new_unchecked(old.xyz()), old.w);
+		let old = self.vec.replace(new)?;
 		#[allow(clippy::float_cmp)]
-		(rot.w != 1.0).then(|| UnitQuaternion::new_unchecked(rot))
+		let rot = (rot.w != 1.0).then(|| UnitQuaternion::new_unchecked(rot))?;
+		// Blend with roll in rim roll zone.
+		Some(rim(self.rim, old, new, max.x.max(max.y) * 0.5, rot))
 	}
 	/// Discards cached normalization of previous cursor/finger position on button/finger release.
 	pub const fn discard(&mut self) {
@@ -150,6 +161,9 @@ impl Orbit<f32> {
 	{
 		Orbit {
 			vec: self.vec.map(|(ray, len)| (ray.cast(), len.to_superset())),
+			rim: self
+				.rim
+				.map(|(inner, outer)| (inner.to_superset(), outer.to_superset())),
 		}
 	}
 }
@@ -192,9 +206,12 @@ impl Orbit<f64> {
 				max.coords.as_ptr(),
 			);
 		}
-		self.vec = Some((Unit::new_unchecked(old.xyz()), old.w));
+		let new = (Unit::new_unchecked(old.xyz()), old.w);
+		let old = self.vec.replace(new)?;
 		#[allow(clippy::float_cmp)]
-		(rot.w != 1.0).then(|| UnitQuaternion::new_unchecked(rot))
+		let rot = (rot.w != 1.0).then(|| UnitQuaternion::new_unchecked(rot))?;
+		// Blend with roll in rim roll zone.
+		Some(rim(self.rim, old, new, max.x.max(max.y) * 0.5, rot))
 	}
 	/// Discards cached normalization of previous cursor/finger position on button/finger release.
 	pub const fn discard(&mut self) {
@@ -208,10 +225,62 @@ impl Orbit<f64> {
 	{
 		Orbit {
 			vec: self.vec.map(|(ray, len)| (ray.cast(), len.to_superset())),
+			rim: self
+				.rim
+				.map(|(inner, outer)| (inner.to_superset(), outer.to_superset())),
 		}
 	}
 }
 
+impl<N: Copy + RealField> Orbit<N> {
+	/// Optional inner and outer radius fraction of rim roll zone. Default is `None`.
+	#[must_use]
+	pub const fn rim(&self) -> Option<(N, N)> {
+		self.rim
+	}
+	/// Sets optional inner and outer radius fraction of rim roll zone. Default is `None`.
+	///
+	/// Positions beyond the outer fraction of the trackball's radius roll around the view axis such
+	/// that the scene follows the cursor/finger circling around the screen's center. Between the
+	/// inner and outer fraction, the induced rotation is smoothly blended from orbit to roll.
+	pub const fn set_rim(&mut self, rim: Option<(N, N)>) {
+		self.rim = rim;
+	}
+}
+
+/// Blends rotation `rot` with roll between `old` and `new` normalized position wrt `rim` zone.
+fn rim<N: Copy + RealField>(
+	rim: Option<(N, N)>,
+	(old_ray, old_len): (Unit<Vector3<N>>, N),
+	(new_ray, new_len): (Unit<Vector3<N>>, N),
+	max: N,
+	rot: UnitQuaternion<N>,
+) -> UnitQuaternion<N> {
+	let Some((inner, outer)) = rim else {
+		return rot;
+	};
+	// Smoothstep weight of roll wrt new radius fraction.
+	let frac = new_len / max;
+	let t = if outer > inner {
+		((frac - inner) / (outer - inner)).clamp(N::zero(), N::one())
+	} else if frac < inner {
+		N::zero()
+	} else {
+		N::one()
+	};
+	let w = t * t * (convert::<_, N>(3.0) - convert::<_, N>(2.0) * t);
+	if w == N::zero() {
+		return rot;
+	}
+	// Counterclockwise angle from old to new position around screen's center.
+	let old = old_ray.xy() * old_len;
+	let new = new_ray.xy() * new_len;
+	let angle = old.perp(&new).atan2(old.dot(&new));
+	// Roll in opposite direction for scene to follow cursor/finger.
+	let roll = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -angle);
+	rot.powf(N::one() - w) * roll.powf(w)
+}
+
 #[cfg(feature = "cc")]
 #[allow(unsafe_code)]
 unsafe extern "C" {
@@ -0,0 +1,123 @@
+use crate::{Clamp, Frame, Plane};
+use heapless::Vec;
+use nalgebra::{Point3, RealField};
+
+/// Convex polytope boundary conditions implementing [`Clamp`].
+///
+/// Implements [`Default`] and can be created with `Polytope::default()` returning an unbounded
+/// polytope of up to `M` boundary planes for target and eye position each.
+///
+/// Each boundary plane in world space bounds a half-space with its normal pointing toward the valid
+/// side, e.g., `Plane::new(Vector3::y_axis(), 0.0)` keeps a position above the floor and
+/// `Plane::new(-Vector3::y_axis(), -3.0)` below the ceiling. The most exceeded plane is returned
+/// so clamped [`Delta`]s glide along one plane after another in corners.
+///
+/// ```
+/// use trackball::{
+///     nalgebra::{Point3, Vector3},
+///     Clamp, Delta, Frame, Plane, Polytope, Scope,
+/// };
+///
+/// // Keeps target above the floor and in front of the wall.
+/// let mut polytope = Polytope::<f64, 2>::default();
+/// polytope.target_planes.push(Plane::new(Vector3::y_axis(), 0.0)).unwrap();
+/// polytope.target_planes.push(Plane::new(-Vector3::x_axis(), -3.0)).unwrap();
+/// let target = Point3::new(0.0, 1.0, 0.0);
+/// let frame = Frame::look_at(target, &Point3::new(0.0, 1.0, 10.0), &Vector3::y());
+/// // Tracks target diagonally through the floor and the wall.
+/// let delta = Delta::Track {
+///     vec: Vector3::new(10.0, -5.0, 1.0),
+/// };
+/// let (delta, _loops) = polytope.compute(&frame, &Scope::default(), &delta).unwrap();
+/// let target = *delta.transform(&frame).target();
+/// // Target glides into the corner while still moving along it.
+/// assert!((target - Point3::new(3.0, 0.0, 1.0)).norm() < 1e-6);
+/// ```
+///
+/// [`Delta`]: crate::Delta
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polytope<N: Copy + RealField, const M: usize> {
+	/// Boundary planes of target position in world space. Default is empty.
+	pub target_planes: Vec<Plane<N>, M>,
+	/// Boundary planes of eye position in world space. Default is empty.
+	pub eye_planes: Vec<Plane<N>, M>,
+	/// Epsilon allowing clamped [`Delta`] to more likely pass revalidation.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	///
+	/// [`Delta`]: crate::Delta
+	/// [AbsDiffEq::default_epsilon()]: approx::AbsDiffEq::default_epsilon()
+	pub hysteresis: N,
+}
+
+impl<N: Copy + RealField, const M: usize> Default for Polytope<N, M> {
+	fn default() -> Self {
+		Self {
+			target_planes: Vec::new(),
+			eye_planes: Vec::new(),
+			hysteresis: N::default_epsilon().sqrt(),
+		}
+	}
+}
+
+impl<N: Copy + RealField, const M: usize> Polytope<N, M> {
+	/// Most exceeded boundary plane of `planes` by `point`.
+	fn exceeded(&self, planes: &[Plane<N>], point: &Point3<N>) -> Option<Plane<N>> {
+		planes
+			.iter()
+			.map(|plane| (plane.distance_from(point), plane))
+			.filter(|&(distance, _plane)| distance > self.hysteresis)
+			.max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+			.map(|(_distance, plane)| *plane)
+	}
+}
+
+impl<N: Copy + RealField, const M: usize> Clamp<N> for Polytope<N, M> {
+	/// Find most exceeded boundary plane by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(&self.target_planes, frame.target())
+	}
+	/// Find most exceeded boundary plane by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(&self.eye_planes, &frame.eye())
+	}
+	/// Up position is unbounded.
+	fn up(&self, _frame: &Frame<N>) -> Option<Plane<N>> {
+		None
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField, const M: usize> rkyv::Archive for Polytope<N, M> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(self.clone() as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField, const M: usize> rkyv::Serialize<Ser>
+	for Polytope<N, M>
+{
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField, const M: usize> rkyv::Deserialize<Self, De>
+	for Polytope<N, M>
+{
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(self.clone()))
+	}
+}
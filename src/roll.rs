@@ -0,0 +1,67 @@
+use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector2, Vector3, convert};
+use simba::scalar::SubsetOf;
+
+/// Roll induced by angular displacement on screen.
+///
+/// Implements [`Default`] and can be created with `Roll::default()`.
+///
+/// Rotates around [`Frame::local_roll_axis()`] such that the scene follows the cursor/finger
+/// circling around the screen's center or a pivot in compliance with the roll angle of
+/// [`crate::Touch`].
+///
+/// Both its methods must be invoked on matching events fired by your 3D graphics library of choice.
+///
+/// [`Frame::local_roll_axis()`]: crate::Frame::local_roll_axis()
+#[derive(Debug, Clone, Default)]
+pub struct Roll<N: Copy + RealField> {
+	/// Caches ray of previous cursor/finger position from pivot.
+	ray: Option<Unit<Vector2<N>>>,
+}
+
+impl<N: Copy + RealField> Roll<N> {
+	/// Computes rotation between previous and current cursor/finger position around the screen's
+	/// center.
+	///
+	/// See [`Self::compute_around()`] with maximum position `max` as screen's width and height.
+	pub fn compute(&mut self, pos: &Point2<N>, max: &Point2<N>) -> Option<UnitQuaternion<N>> {
+		self.compute_around(pos, &(max * convert(0.5)))
+	}
+	/// Computes rotation between previous and current cursor/finger position around `pivot`.
+	///
+	/// Ray of previous position is cached and has to be discarded on button/finger release via
+	/// [`Self::discard()`]. Positions are in screen space, see [`crate::Orbit::compute()`].
+	/// Apply the rotation in camera space via [`Frame::local_orbit()`].
+	///
+	/// Returns `None`:
+	///
+	///   * on first invocation and after [`Self::discard()`] as there is no previous position yet,
+	///   * in case that the position coincides with the pivot,
+	///   * in the unlikely case that a position event fires twice resulting in zero displacements.
+	///
+	/// [`Frame::local_orbit()`]: crate::Frame::local_orbit()
+	pub fn compute_around(
+		&mut self,
+		pos: &Point2<N>,
+		pivot: &Point2<N>,
+	) -> Option<UnitQuaternion<N>> {
+		let new_ray = Unit::try_new(pos - pivot, N::zero())?;
+		let old_ray = self.ray.replace(new_ray)?;
+		// Roll angle in opposite direction around pivot.
+		let rot = old_ray.perp(&new_ray).atan2(old_ray.dot(&new_ray));
+		(rot != N::zero()).then(|| UnitQuaternion::from_axis_angle(&Vector3::z_axis(), rot))
+	}
+	/// Discards cached ray of previous cursor/finger position on button/finger release.
+	pub const fn discard(&mut self) {
+		self.ray = None;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Roll<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Roll {
+			ray: self.ray.map(Unit::<Vector2<N>>::cast),
+		}
+	}
+}
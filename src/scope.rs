@@ -1,12 +1,27 @@
 use crate::Fixed;
-use nalgebra::{Matrix4, Point2, RealField, convert};
+use nalgebra::{Matrix4, Point2, RealField, Vector2, convert};
 use simba::scalar::SubsetOf;
 
+/// Depth range of normalized device coordinates.
+///
+/// Implements [`Default`] and can be created with `Depth::default()` returning
+/// `Depth::NegOneToOne`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Depth {
+	/// Depth range from -1 to 1 as in OpenGL (default).
+	#[default]
+	NegOneToOne,
+	/// Depth range from 0 to 1 as in Vulkan, Direct3D, Metal, and WebGPU.
+	ZeroToOne,
+}
+
 /// Scope defining enclosing viewing frustum.
 ///
 /// Implements [`Default`] and can be created with `Scope::default()`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Scope<N: Copy + RealField> {
 	/// Fixed quantity wrt field of view.
 	///
@@ -25,6 +40,26 @@ pub struct Scope<N: Copy + RealField> {
 	///
 	/// Computes scale-identical orthographic instead of perspective projection. Default is `false`.
 	opm: bool,
+	/// Reversed depth mode.
+	///
+	/// Maps near clip plane to far and far clip plane to near depth. Default is `false`.
+	rev: bool,
+	/// Infinite far clip plane mode.
+	///
+	/// Ignores far clip plane distance in perspective projection mode. Default is `false`.
+	inf: bool,
+	/// Depth range of normalized device coordinates. Default is [`Depth::NegOneToOne`].
+	dep: Depth,
+	/// Flipped y-axis mode.
+	///
+	/// Points y-axis of normalized device coordinates downward. Default is `false`.
+	flip: bool,
+	/// Sub-rectangle of screen as minimum and maximum fractions of screen's width and height.
+	///
+	/// Default is `(0, 0)` to `(1, 1)` covering the full screen.
+	tile: (Point2<N>, Point2<N>),
+	/// Lens shift as fraction of screen's width and height. Default is zero.
+	lens: Vector2<N>,
 }
 
 impl<N: Copy + RealField> Default for Scope<N> {
@@ -34,6 +69,12 @@ impl<N: Copy + RealField> Default for Scope<N> {
 			zcp: (convert(1e-1), convert(1e+3)),
 			oim: false,
 			opm: false,
+			rev: false,
+			inf: false,
+			dep: Depth::default(),
+			flip: false,
+			tile: (Point2::origin(), Point2::new(N::one(), N::one())),
+			lens: Vector2::zeros(),
 		}
 	}
 }
@@ -114,22 +155,227 @@ impl<N: Copy + RealField> Scope<N> {
 	pub const fn set_ortho(&mut self, opm: bool) {
 		self.opm = opm;
 	}
+	/// Reversed depth mode.
+	///
+	/// Maps near clip plane to far and far clip plane to near depth. Default is `false`.
+	#[must_use]
+	pub const fn reversed(&self) -> bool {
+		self.rev
+	}
+	/// Sets reversed depth mode.
+	///
+	/// Maps near clip plane to far and far clip plane to near depth for a more uniform precision of
+	/// floating-point depth buffers. Default is `false`.
+	pub const fn set_reversed(&mut self, rev: bool) {
+		self.rev = rev;
+	}
+	/// Infinite far clip plane mode.
+	///
+	/// Ignores far clip plane distance in perspective projection mode. Default is `false`.
+	#[must_use]
+	pub const fn infinite(&self) -> bool {
+		self.inf
+	}
+	/// Sets infinite far clip plane mode.
+	///
+	/// Ignores far clip plane distance in perspective projection mode. Default is `false`.
+	pub const fn set_infinite(&mut self, inf: bool) {
+		self.inf = inf;
+	}
+	/// Depth range of normalized device coordinates. Default is [`Depth::NegOneToOne`].
+	#[must_use]
+	pub const fn depth(&self) -> Depth {
+		self.dep
+	}
+	/// Sets depth range of normalized device coordinates. Default is [`Depth::NegOneToOne`].
+	pub const fn set_depth(&mut self, dep: Depth) {
+		self.dep = dep;
+	}
+	/// Flipped y-axis mode.
+	///
+	/// Points y-axis of normalized device coordinates downward. Default is `false`.
+	#[must_use]
+	pub const fn flipped(&self) -> bool {
+		self.flip
+	}
+	/// Sets flipped y-axis mode.
+	///
+	/// Points y-axis of normalized device coordinates downward as in Vulkan. Default is `false`.
+	///
+	/// ```
+	/// use trackball::{Depth, Scope};
+	///
+	/// // OpenGL (default).
+	/// let opengl = Scope::<f32>::default();
+	/// // Direct3D, Metal, and WebGPU.
+	/// let mut direct3d = Scope::<f32>::default();
+	/// direct3d.set_depth(Depth::ZeroToOne);
+	/// // Vulkan.
+	/// let mut vulkan = direct3d;
+	/// vulkan.set_flipped(true);
+	/// ```
+	///
+	/// See [`Image::set_left_handed()`] for left-handed world space.
+	///
+	/// [`Image::set_left_handed()`]: crate::Image::set_left_handed()
+	pub const fn set_flipped(&mut self, flip: bool) {
+		self.flip = flip;
+	}
+	/// Sub-rectangle of screen as minimum and maximum fractions of screen's width and height, see
+	/// [`Self::set_tile()`].
+	#[must_use]
+	pub const fn tile(&self) -> (Point2<N>, Point2<N>) {
+		self.tile
+	}
+	/// Sets sub-rectangle of screen as minimum and maximum fractions of screen's width and height.
+	///
+	/// Fractions are in screen space from left to right and top to bottom. Computes an asymmetric
+	/// frustum covering only the sub-rectangle of the full virtual screen, e.g., for tiled
+	/// screenshots and multi-monitor walls, whereas positions in screen space and the unit per
+	/// pixel on focus plane remain wrt the full virtual screen. Default is `(0, 0)` to `(1, 1)`
+	/// covering the full screen. See [`Self::set_shift()`] for shifting the screen along.
+	///
+	/// ```
+	/// use nalgebra::{Point2, Point3};
+	/// use trackball::Scope;
+	///
+	/// // Top right tile of 2x2 tiled screenshot.
+	/// let mut scope = Scope::<f32>::default();
+	/// scope.set_tile(Point2::new(0.5, 0.0), Point2::new(1.0, 0.5));
+	/// // Center and top right corner of full screen on focus plane in camera space.
+	/// let (zat, max) = (10.0, Point2::new(800.0, 600.0));
+	/// let (half, _upp) = scope.fov().max_and_upp(zat, &max);
+	/// let center = Point3::new(0.0, 0.0, -zat);
+	/// let corner = Point3::new(half.x, half.y, -zat);
+	/// // Map onto bottom left and top right corner of tile in normalized device coordinates.
+	/// let (mat, _upp) = scope.projection_and_upp(zat, &max);
+	/// let center = mat.transform_point(&center);
+	/// let corner = mat.transform_point(&corner);
+	/// assert!((center.x + 1.0).abs() < 1e-5 && (center.y + 1.0).abs() < 1e-5);
+	/// assert!((corner.x - 1.0).abs() < 1e-5 && (corner.y - 1.0).abs() < 1e-5);
+	/// ```
+	pub const fn set_tile(&mut self, min: Point2<N>, max: Point2<N>) {
+		self.tile = (min, max);
+	}
+	/// Lens shift as fraction of screen's width and height, see [`Self::set_shift()`].
+	#[must_use]
+	pub const fn shift(&self) -> &Vector2<N> {
+		&self.lens
+	}
+	/// Sets lens shift as fraction of screen's width and height.
+	///
+	/// Fractions are in screen space from left to right and top to bottom. Computes an asymmetric
+	/// frustum shifted along with the screen such that positions in screen space are wrt the
+	/// shifted screen, e.g., for off-axis stereo or architectural photography. Default is zero.
+	pub const fn set_shift(&mut self, lens: Vector2<N>) {
+		self.lens = lens;
+	}
 	/// Projection transformation and unit per pixel on focus plane wrt distance between eye and
 	/// target and maximum position in screen space.
+	///
+	/// Maps clip planes wrt [`Self::depth()`], [`Self::reversed()`], and [`Self::infinite()`],
+	/// shifts and narrows frustum wrt [`Self::shift()`] and [`Self::tile()`], and flips y-axis wrt
+	/// [`Self::flipped()`].
+	///
+	/// ```
+	/// use trackball::{
+	///     nalgebra::{Point2, Vector4},
+	///     Depth, Scope,
+	/// };
+	///
+	/// let (zat, max) = (10.0, Point2::new(800.0, 600.0));
+	/// for depth in [Depth::NegOneToOne, Depth::ZeroToOne] {
+	///     for rev in [false, true] {
+	///         for inf in [false, true] {
+	///             let mut scope = Scope::default();
+	///             scope.set_depth(depth);
+	///             scope.set_reversed(rev);
+	///             scope.set_infinite(inf);
+	///             let (znear, zfar) = scope.clip_planes(zat);
+	///             let (mat, _upp) = scope.projection_and_upp(zat, &max);
+	///             // Depth in normalized device coordinates of position or direction along view.
+	///             let ndc = |z: f64, w: f64| {
+	///                 let clip = mat * Vector4::new(0.0, 0.0, -z, w);
+	///                 clip.z / clip.w
+	///             };
+	///             // Expected depth of near and far clip plane.
+	///             let (near, far) = match depth {
+	///                 Depth::NegOneToOne => (-1.0, 1.0),
+	///                 Depth::ZeroToOne => (0.0, 1.0),
+	///             };
+	///             let (near, far) = if rev { (far, near) } else { (near, far) };
+	///             // Expected hyperbolic depth of focus plane.
+	///             let focus = if inf {
+	///                 far + (near - far) * znear / zat
+	///             } else {
+	///                 (far * zfar * (zat - znear) + near * znear * (zfar - zat))
+	///                     / (zat * (zfar - znear))
+	///             };
+	///             assert!((ndc(znear, 1.0) - near).abs() < 1e-9);
+	///             assert!((ndc(zat, 1.0) - focus).abs() < 1e-9);
+	///             if inf {
+	///                 // Infinity maps onto far clip plane.
+	///                 assert!((ndc(1.0, 0.0) - far).abs() < 1e-9);
+	///             } else {
+	///                 // Infinity maps beyond far clip plane.
+	///                 assert!((ndc(zfar, 1.0) - far).abs() < 1e-9);
+	///                 assert!((ndc(1.0, 0.0) - far) * (far - near) > 0.0);
+	///             }
+	///         }
+	///     }
+	/// }
+	/// ```
 	#[must_use]
 	pub fn projection_and_upp(&self, zat: N, max: &Point2<N>) -> (Matrix4<N>, N) {
 		let (znear, zfar) = self.clip_planes(zat);
+		// Depth of near and far clip plane in normalized device coordinates.
+		let (near, far) = match self.dep {
+			Depth::NegOneToOne => (-N::one(), N::one()),
+			Depth::ZeroToOne => (N::zero(), N::one()),
+		};
+		let (near, far) = if self.rev { (far, near) } else { (near, far) };
 		if self.opm {
 			let (max, upp) = self.fov.max_and_upp(zat, max);
-			let mat = Matrix4::new_orthographic(-max.x, max.x, -max.y, max.y, znear, zfar);
+			let mut mat = Matrix4::new_orthographic(-max.x, max.x, -max.y, max.y, znear, zfar);
+			// Linear depth mapping of negative z-axis in camera space.
+			let scale = (near - far) / (zfar - znear);
+			mat[(2, 2)] = scale;
+			mat[(2, 3)] = near + scale * znear;
+			self.narrow(&mut mat);
 			(mat, upp)
 		} else {
 			let fov = self.fov.to_ver(max).into_inner();
 			let (max, upp) = self.fov.max_and_upp(zat, max);
-			let mat = Matrix4::new_perspective(max.x / max.y, fov, znear, zfar);
+			let mut mat = Matrix4::new_perspective(max.x / max.y, fov, znear, zfar);
+			// Hyperbolic depth mapping of negative z-axis in camera space.
+			let scale = if self.inf {
+				-far
+			} else {
+				(near * znear - far * zfar) / (zfar - znear)
+			};
+			mat[(2, 2)] = scale;
+			mat[(2, 3)] = (near + scale) * znear;
+			self.narrow(&mut mat);
 			(mat, upp)
 		}
 	}
+	/// Shifts and narrows frustum of projection transformation wrt [`Self::shift()`] and
+	/// [`Self::tile()`] and flips y-axis wrt [`Self::flipped()`].
+	fn narrow(&self, mat: &mut Matrix4<N>) {
+		// Center and half size of sub-rectangle in normalized device coordinates of full screen.
+		let (min, max) = self.tile;
+		let (min, max) = (min + self.lens, max + self.lens);
+		let center = Point2::new(min.x + max.x - N::one(), N::one() - min.y - max.y);
+		let half = max - min;
+		let w = mat.row(3).into_owned();
+		for (i, center, half) in [(0, center.x, half.x), (1, center.y, half.y)] {
+			let row = (mat.row(i) - w.scale(center)).unscale(half);
+			mat.set_row(i, &row);
+		}
+		if self.flip {
+			mat.row_mut(1).neg_mut();
+		}
+	}
 	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
 	#[must_use]
 	pub fn cast<M: Copy + RealField>(self) -> Scope<M>
@@ -142,6 +388,12 @@ impl<N: Copy + RealField> Scope<N> {
 			zcp: (near.to_superset(), far.to_superset()),
 			oim: self.oim,
 			opm: self.opm,
+			rev: self.rev,
+			inf: self.inf,
+			dep: self.dep,
+			flip: self.flip,
+			tile: (self.tile.0.cast(), self.tile.1.cast()),
+			lens: self.lens.cast(),
 		}
 	}
 }
@@ -175,3 +427,33 @@ impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, D
 		Ok(rkyv::from_archived!(*self))
 	}
 }
+
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for Depth {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized> rkyv::Serialize<Ser> for Depth {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized> rkyv::Deserialize<Self, De> for Depth {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
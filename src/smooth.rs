@@ -0,0 +1,149 @@
+use crate::{Delta, Frame};
+use approx::AbsDiffEq;
+use nalgebra::{RealField, convert};
+use simba::scalar::SubsetOf;
+
+/// Smooth transition of current toward target [`Frame`] via time-based exponential smoothing.
+///
+/// Damps jittery input of operation handlers by applying their [`Delta`]s to the target frame
+/// while the current frame follows it per tick, see [`Frame::try_lerp_slerp()`].
+///
+/// Invoke [`Self::compute()`] once per rendered frame and render its returned frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Smooth<N: Copy + RealField> {
+	/// Current frame.
+	old: Frame<N>,
+	/// Target frame.
+	new: Frame<N>,
+	/// Time constant in seconds to cover 63.2 % of the remaining transition. Default is `0.05`.
+	tau: N,
+	/// Epsilon below which current and target frame are considered settled.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	eps: N,
+}
+
+impl<N: Copy + RealField> Smooth<N> {
+	/// Settled smoothing at `frame`.
+	#[must_use]
+	pub fn new(frame: Frame<N>) -> Self {
+		Self {
+			old: frame,
+			new: frame,
+			tau: convert(0.05),
+			eps: N::default_epsilon().sqrt(),
+		}
+	}
+	/// Current frame.
+	#[must_use]
+	pub const fn frame(&self) -> &Frame<N> {
+		&self.old
+	}
+	/// Target frame.
+	#[must_use]
+	pub const fn target(&self) -> &Frame<N> {
+		&self.new
+	}
+	/// Sets target frame the current frame follows.
+	pub const fn set_target(&mut self, frame: Frame<N>) {
+		self.new = frame;
+	}
+	/// Applies delta transform to target frame.
+	pub fn apply(&mut self, delta: &Delta<N>) {
+		self.new = delta.transform(&self.new);
+	}
+	/// Sets current and target frame without smoothing.
+	pub const fn reset(&mut self, frame: Frame<N>) {
+		self.old = frame;
+		self.new = frame;
+	}
+	/// Computes current frame after tick of duration `dt` in seconds.
+	///
+	/// Snaps to target frame once settled or if the angle between both rotations is 180 degrees.
+	pub fn compute(&mut self, dt: N) -> &Frame<N> {
+		let t = if self.tau > N::zero() {
+			N::one() - (-dt / self.tau).exp()
+		} else {
+			N::one()
+		};
+		self.old = self
+			.old
+			.try_lerp_slerp(&self.new, t, N::default_epsilon())
+			.filter(|frame| !frame.abs_diff_eq(&self.new, self.eps))
+			.unwrap_or(self.new);
+		&self.old
+	}
+	/// Whether current frame has settled at target frame.
+	#[must_use]
+	pub fn settled(&self) -> bool {
+		self.old == self.new
+	}
+	/// Time constant in seconds to cover 63.2 % of the remaining transition. Default is `0.05`.
+	#[must_use]
+	pub const fn time_constant(&self) -> N {
+		self.tau
+	}
+	/// Sets time constant in seconds to cover 63.2 % of the remaining transition.
+	///
+	/// Default is `0.05`. Zero disables smoothing.
+	pub const fn set_time_constant(&mut self, tau: N) {
+		self.tau = tau;
+	}
+	/// Epsilon below which current and target frame are considered settled.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	#[must_use]
+	pub const fn epsilon(&self) -> N {
+		self.eps
+	}
+	/// Sets epsilon below which current and target frame are considered settled.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	pub const fn set_epsilon(&mut self, eps: N) {
+		self.eps = eps;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Smooth<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Smooth {
+			old: self.old.cast(),
+			new: self.new.cast(),
+			tau: self.tau.to_superset(),
+			eps: self.eps.to_superset(),
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Smooth<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Smooth<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De> for Smooth<N> {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
@@ -0,0 +1,273 @@
+use crate::{Frame, Image, Scope};
+use nalgebra::{Point2, RealField, Vector2, Vector3};
+use simba::scalar::SubsetOf;
+
+/// Stereo rig converging the views of both eyes.
+///
+/// Implements [`Default`] and can be created with `Rig::default()` returning `Rig::OffAxis`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rig {
+	/// Parallel views with symmetric frustums converging at infinity.
+	Parallel,
+	/// Views rotated toward convergence point with symmetric frustums.
+	///
+	/// Introduces vertical parallax toward the screen's corners.
+	ToeIn,
+	/// Parallel views with asymmetric frustums converging at convergence distance (default).
+	#[default]
+	OffAxis,
+}
+
+/// Stereoscopic camera pair as left and right eye [`Image`] of [`Frame`] and [`Scope`].
+///
+/// Derives frame and scope of each eye from the frame and scope of the central eye by offsetting
+/// the eye by half the interocular distance along [`Frame::pitch_axis()`] and by converging both
+/// views wrt [`Rig`] at the convergence distance. Screen space positions of either eye image are
+/// wrt the screen of that eye, see [`Scope::set_shift()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stereo<N: Copy + RealField> {
+	/// Stereo rig. Default is [`Rig::OffAxis`].
+	rig: Rig,
+	/// Interocular distance.
+	iod: N,
+	/// Convergence distance from eye. Default is `None` converging at [`Frame::distance()`].
+	cvd: Option<N>,
+	/// Cached image of left eye.
+	left: Image<N>,
+	/// Cached image of right eye.
+	right: Image<N>,
+}
+
+impl<N: Copy + RealField> Stereo<N> {
+	/// Computes initial images of both eyes from frame, scope, and screen's width and height wrt
+	/// interocular distance `iod`.
+	///
+	/// ```
+	/// use nalgebra::{Point2, Point3, Vector3};
+	/// use trackball::{Frame, Scope, Stereo};
+	///
+	/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 5.0), &Vector3::y());
+	/// let scope = Scope::default();
+	/// let stereo = Stereo::new(&frame, &scope, Point2::new(800.0, 600.0), 0.065);
+	/// // Per-eye projection view matrices.
+	/// let (left, right) = (stereo.left().transformation(), stereo.right().transformation());
+	/// ```
+	#[must_use]
+	pub fn new(frame: &Frame<N>, scope: &Scope<N>, max: Point2<N>, iod: N) -> Self {
+		let rig = Rig::default();
+		let [(left_frame, left_scope), (right_frame, right_scope)] =
+			eyes(rig, iod, frame.distance(), frame, scope, &max);
+		Self {
+			rig,
+			iod,
+			cvd: None,
+			left: Image::new(&left_frame, &left_scope, max),
+			right: Image::new(&right_frame, &right_scope, max),
+		}
+	}
+	/// Recomputes only cached matrices of both eye images whose parameters have changed, see
+	/// [`Image::compute()`].
+	///
+	/// Returns `Some(true)` on success, `Some(false)` on failure, and `None` with no changes.
+	pub fn compute(&mut self, frame: Frame<N>, scope: Scope<N>) -> Option<bool> {
+		let [(left_frame, left_scope), (right_frame, right_scope)] = self.eyes(&frame, &scope);
+		let left = self.left.compute(left_frame, left_scope);
+		let right = self.right.compute(right_frame, right_scope);
+		match (left, right) {
+			(None, None) => None,
+			(left, right) => Some(left.unwrap_or(true) && right.unwrap_or(true)),
+		}
+	}
+	/// Frame and scope of left and right eye derived from frame and scope of central eye.
+	#[must_use]
+	pub fn eyes(&self, frame: &Frame<N>, scope: &Scope<N>) -> [(Frame<N>, Scope<N>); 2] {
+		let cvd = self.convergence(frame);
+		eyes(self.rig, self.iod, cvd, frame, scope, self.left.max())
+	}
+	/// Cached image of left eye.
+	#[must_use]
+	pub const fn left(&self) -> &Image<N> {
+		&self.left
+	}
+	/// Cached image of right eye.
+	#[must_use]
+	pub const fn right(&self) -> &Image<N> {
+		&self.right
+	}
+	/// Sets whether to compute transformation and inverse transformation of both eye images, see
+	/// [`Image::set_compute()`].
+	pub const fn set_compute(&mut self, compute_mat: bool, compute_inv: bool) {
+		self.left.set_compute(compute_mat, compute_inv);
+		self.right.set_compute(compute_mat, compute_inv);
+	}
+	/// Sets whether to use passive transformations of both eye images, see
+	/// [`Image::set_passive()`].
+	pub const fn set_passive(&mut self, use_passive: bool) {
+		self.left.set_passive(use_passive);
+		self.right.set_passive(use_passive);
+	}
+	/// Sets whether to use left-handed world space of both eye images, see
+	/// [`Image::set_left_handed()`].
+	pub fn set_left_handed(&mut self, use_left_handed: bool) {
+		self.left.set_left_handed(use_left_handed);
+		self.right.set_left_handed(use_left_handed);
+	}
+	/// Sets current position in screen space of hovering input or pointing device of both eye
+	/// images.
+	pub const fn set_pos(&mut self, pos: Point2<N>) {
+		self.left.set_pos(pos);
+		self.right.set_pos(pos);
+	}
+	/// Sets maximum position in screen space as screen's width and height of both eye images.
+	pub fn set_max(&mut self, max: Point2<N>) {
+		self.left.set_max(max);
+		self.right.set_max(max);
+	}
+	/// Sets sub-pixel jitter offset in screen space of both eye images, see
+	/// [`Image::set_jitter()`].
+	pub fn set_jitter(&mut self, jitter: Vector2<N>) {
+		self.left.set_jitter(jitter);
+		self.right.set_jitter(jitter);
+	}
+	/// Stereo rig. Default is [`Rig::OffAxis`].
+	#[must_use]
+	pub const fn rig(&self) -> Rig {
+		self.rig
+	}
+	/// Sets stereo rig. Default is [`Rig::OffAxis`].
+	pub const fn set_rig(&mut self, rig: Rig) {
+		self.rig = rig;
+	}
+	/// Interocular distance.
+	#[must_use]
+	pub const fn interocular_distance(&self) -> N {
+		self.iod
+	}
+	/// Sets interocular distance.
+	pub const fn set_interocular_distance(&mut self, iod: N) {
+		self.iod = iod;
+	}
+	/// Convergence distance from eye wrt frame, see [`Self::set_convergence()`].
+	#[must_use]
+	pub fn convergence(&self, frame: &Frame<N>) -> N {
+		self.cvd.unwrap_or_else(|| frame.distance())
+	}
+	/// Sets convergence distance from eye.
+	///
+	/// Default is `None` converging at [`Frame::distance()`]. Ignored by [`Rig::Parallel`].
+	pub const fn set_convergence(&mut self, cvd: Option<N>) {
+		self.cvd = cvd;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Stereo<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Stereo {
+			rig: self.rig,
+			iod: self.iod.to_superset(),
+			cvd: self.cvd.map(|cvd| cvd.to_superset()),
+			left: self.left.cast(),
+			right: self.right.cast(),
+		}
+	}
+}
+
+/// Frame and scope of left and right eye offset by half the interocular distance `iod` and
+/// converging wrt `rig` at convergence distance `cvd` from eye and screen's width and height `max`.
+fn eyes<N: Copy + RealField>(
+	rig: Rig,
+	iod: N,
+	cvd: N,
+	frame: &Frame<N>,
+	scope: &Scope<N>,
+	max: &Point2<N>,
+) -> [(Frame<N>, Scope<N>); 2] {
+	let two = N::one() + N::one();
+	[-iod / two, iod / two].map(|off| {
+		let mut eye_frame = *frame;
+		let mut eye_scope = *scope;
+		match rig {
+			Rig::Parallel => eye_frame.local_slide(&Vector3::new(off, N::zero(), N::zero())),
+			Rig::ToeIn => {
+				let eye = frame.eye() + frame.pitch_axis().scale(off);
+				let dir = frame.eye() - frame.roll_axis().scale(cvd) - eye;
+				let target = eye + dir.normalize().scale(frame.distance());
+				eye_frame = Frame::look_at(target, &eye, &frame.yaw_axis());
+			}
+			Rig::OffAxis => {
+				eye_frame.local_slide(&Vector3::new(off, N::zero(), N::zero()));
+				// Half width of frustum at convergence distance.
+				let zat = if scope.ortho() { frame.distance() } else { cvd };
+				let (max, _upp) = scope.fov().max_and_upp(zat, max);
+				// Shift frustum toward convergence point as fraction of screen's width.
+				let vec = Vector2::new(-off / (two * max.x), N::zero());
+				eye_scope.set_shift(scope.shift() + vec);
+			}
+		}
+		(eye_frame, eye_scope)
+	})
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Stereo<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Stereo<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De> for Stereo<N> {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl rkyv::Archive for Rig {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized> rkyv::Serialize<Ser> for Rig {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized> rkyv::Deserialize<Self, De> for Rig {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
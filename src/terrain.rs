@@ -0,0 +1,117 @@
+use crate::{Clamp, Frame, Plane};
+use core::fmt::{self, Debug};
+use nalgebra::{Isometry3, Point3, RealField, Unit, Vector3};
+
+/// Height function of [`Terrain`] with y-axis as up axis.
+///
+/// Implemented for closures and functions of type `Fn(N, N) -> N`.
+pub trait Height<N: Copy + RealField>: Send + Sync + 'static {
+	/// Height of terrain at `x` and `z` coordinates.
+	#[must_use]
+	fn height(&self, x: N, z: N) -> N;
+	/// Upward unit normal of terrain at `x` and `z` coordinates.
+	///
+	/// Default approximates the normal via central differences of [`Self::height()`] with steps
+	/// relative to the magnitude of the coordinates.
+	#[must_use]
+	fn normal(&self, x: N, z: N) -> Unit<Vector3<N>> {
+		let two = N::one() + N::one();
+		let step = N::default_epsilon().sqrt();
+		let (step_x, step_z) = (step * x.abs().max(N::one()), step * z.abs().max(N::one()));
+		let dx = (self.height(x + step_x, z) - self.height(x - step_x, z)) / (two * step_x);
+		let dz = (self.height(x, z + step_z) - self.height(x, z - step_z)) / (two * step_z);
+		Unit::new_normalize(Vector3::new(-dx, N::one(), -dz))
+	}
+}
+
+impl<N: Copy + RealField, F: Fn(N, N) -> N + Send + Sync + 'static> Height<N> for F {
+	fn height(&self, x: N, z: N) -> N {
+		self(x, z)
+	}
+}
+
+/// Terrain boundary conditions implementing [`Clamp`].
+///
+/// Keeps target and eye position a minimum altitude above a heightfield, e.g., for ground-following
+/// cameras. The exceeded boundary plane is the local tangent plane of the heightfield offset by
+/// the minimum altitude, so clamped [`Delta`]s glide over hills.
+///
+/// [`Delta`]: crate::Delta
+#[derive(Clone)]
+pub struct Terrain<N: Copy + RealField, H: Height<N>> {
+	/// Height function of terrain.
+	pub height: H,
+	/// Isometry in world space of terrain inversely transforming target and eye positions.
+	///
+	/// Default is identity.
+	pub transform: Isometry3<N>,
+	/// Minimum altitude of target position above terrain. Default is `N::zero()`.
+	pub min_target_altitude: N,
+	/// Minimum altitude of eye position above terrain. Default is `N::zero()`.
+	pub min_eye_altitude: N,
+	/// Epsilon allowing clamped [`Delta`] to more likely pass revalidation.
+	///
+	/// Default is <code>[AbsDiffEq::default_epsilon()].sqrt()</code>.
+	///
+	/// [`Delta`]: crate::Delta
+	/// [AbsDiffEq::default_epsilon()]: approx::AbsDiffEq::default_epsilon()
+	pub hysteresis: N,
+}
+
+impl<N: Copy + RealField, H: Height<N>> Terrain<N, H> {
+	/// Terrain of `height` function with default parameters.
+	///
+	/// ```
+	/// use trackball::Terrain;
+	///
+	/// // Keeps eye 1.5 units above rolling hills.
+	/// let terrain = Terrain {
+	///     min_eye_altitude: 1.5,
+	///     ..Terrain::new(|x: f32, z: f32| x.sin() * z.cos())
+	/// };
+	/// ```
+	#[must_use]
+	pub fn new(height: H) -> Self {
+		Self {
+			height,
+			transform: Isometry3::default(),
+			min_target_altitude: N::zero(),
+			min_eye_altitude: N::zero(),
+			hysteresis: N::default_epsilon().sqrt(),
+		}
+	}
+	/// Tangent boundary plane in world space if `point` in world space is below `altitude`.
+	fn exceeded(&self, altitude: N, point: &Point3<N>) -> Option<Plane<N>> {
+		let point = self.transform.inverse_transform_point(point);
+		let (x, z) = (point.x, point.z);
+		let ground = Point3::new(x, self.height.height(x, z) + altitude, z);
+		let plane = Plane::with_point(self.height.normal(x, z), &ground);
+		(plane.distance_from(&point) > self.hysteresis).then(|| plane.transform_by(&self.transform))
+	}
+}
+
+impl<N: Copy + RealField, H: Height<N>> Debug for Terrain<N, H> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Terrain")
+			.field("transform", &self.transform)
+			.field("min_target_altitude", &self.min_target_altitude)
+			.field("min_eye_altitude", &self.min_eye_altitude)
+			.field("hysteresis", &self.hysteresis)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<N: Copy + RealField, H: Height<N>> Clamp<N> for Terrain<N, H> {
+	/// Find tangent boundary plane exceeded by target position.
+	fn target(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(self.min_target_altitude, frame.target())
+	}
+	/// Find tangent boundary plane exceeded by eye position.
+	fn eye(&self, frame: &Frame<N>) -> Option<Plane<N>> {
+		self.exceeded(self.min_eye_altitude, &frame.eye())
+	}
+	/// Up position is unbounded.
+	fn up(&self, _frame: &Frame<N>) -> Option<Plane<N>> {
+		None
+	}
+}
@@ -0,0 +1,333 @@
+use crate::{Fixed, Frame, Scope};
+use nalgebra::{RealField, convert};
+use simba::scalar::SubsetOf;
+
+/// Easing curve mapping linear time onto interpolation parameter.
+///
+/// Implements [`Default`] and can be created with `Ease::default()` returning
+/// `Ease::CubicInOut`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ease<N: Copy + RealField> {
+	/// Constant speed.
+	Linear,
+	/// Cubic acceleration until half-time and cubic deceleration afterwards (default).
+	#[default]
+	CubicInOut,
+	/// Exponential acceleration until half-time and exponential deceleration afterwards.
+	ExpoInOut,
+	/// Damped spring overshooting the final state with damping ratio between 0 and 1.
+	///
+	/// A damping ratio of 1 or more is critically damped and does not overshoot. A damping ratio
+	/// below 0.1 is clamped to 0.1 as lighter damping oscillates too fast to settle smoothly. The
+	/// step response is normalized by its value at the end to settle exactly on the final state.
+	Spring(N),
+}
+
+impl<N: Copy + RealField> Ease<N> {
+	/// Interpolation parameter wrt linear time `t` clamped between 0 and 1.
+	///
+	/// Yields 0 at 0 and 1 at 1 and may overshoot 1 inbetween for [`Self::Spring`].
+	#[must_use]
+	pub fn ease(&self, t: N) -> N {
+		let two = N::one() + N::one();
+		let t = t.clamp(N::zero(), N::one());
+		if t == N::zero() || t == N::one() {
+			return t;
+		}
+		match *self {
+			Self::Linear => t,
+			Self::CubicInOut => {
+				if t < convert(0.5) {
+					t.powi(3) * convert(4.0)
+				} else {
+					N::one() - (-two * t + two).powi(3) / two
+				}
+			}
+			Self::ExpoInOut => {
+				let ten: N = convert(10.0);
+				if t < convert(0.5) {
+					two.powf(ten * two * t - ten) / two
+				} else {
+					(two - two.powf(-ten * two * t + ten)) / two
+				}
+			}
+			Self::Spring(zeta) => {
+				// Undamped spring never settles and lightly damped one oscillates too fast.
+				let zeta = zeta.max(convert(0.1));
+				// Angular frequency decaying the envelope to 1e-3 at the end.
+				let omega = convert::<_, N>(1e3).ln() / zeta.min(N::one());
+				// Step response of spring.
+				let step = |t: N| {
+					let decay = (-zeta * omega * t).exp();
+					if zeta < N::one() {
+						// Damped angular frequency of under-damped spring.
+						let omega_d = omega * (N::one() - zeta * zeta).sqrt();
+						let (sin, cos) = (omega_d * t).sin_cos();
+						N::one() - decay * (cos + zeta * omega / omega_d * sin)
+					} else {
+						N::one() - decay * (N::one() + omega * t)
+					}
+				};
+				// Normalized to be continuous at the end.
+				step(t) / step(N::one())
+			}
+		}
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Ease<M>
+	where
+		N: SubsetOf<M>,
+	{
+		match self {
+			Self::Linear => Ease::Linear,
+			Self::CubicInOut => Ease::CubicInOut,
+			Self::ExpoInOut => Ease::ExpoInOut,
+			Self::Spring(zeta) => Ease::Spring(zeta.to_superset()),
+		}
+	}
+}
+
+/// Animated transition between two [`Frame`]s and optionally two [`Scope`]s over time.
+///
+/// Invoke [`Self::compute()`] once per rendered frame until it returns `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition<N: Copy + RealField> {
+	/// Initial and final frame.
+	frames: (Frame<N>, Frame<N>),
+	/// Optional initial and final scope.
+	scopes: Option<(Scope<N>, Scope<N>)>,
+	/// Easing curve. Default is [`Ease::CubicInOut`].
+	ease: Ease<N>,
+	/// Duration in seconds.
+	dur: N,
+	/// Elapsed time in seconds or `None` when finished.
+	time: Option<N>,
+	/// Zoom-out at half-time as ratio of distance between initial and final target.
+	fly: N,
+}
+
+impl<N: Copy + RealField> Transition<N> {
+	/// Transition from `old` to `new` frame over duration `dur` in seconds.
+	#[must_use]
+	pub fn new(old: Frame<N>, new: Frame<N>, dur: N) -> Self {
+		Self {
+			frames: (old, new),
+			scopes: None,
+			ease: Ease::default(),
+			dur,
+			time: Some(N::zero()),
+			fly: N::zero(),
+		}
+	}
+	/// Transition from `old` to `new` frame over duration `dur` in seconds zooming out along the
+	/// way like map viewers do.
+	///
+	/// Zooms out at half-time by the distance between initial and final target, see
+	/// [`Self::set_fly()`].
+	#[must_use]
+	pub fn fly_to(old: Frame<N>, new: Frame<N>, dur: N) -> Self {
+		let mut transition = Self::new(old, new, dur);
+		transition.fly = N::one();
+		transition
+	}
+	/// Sets initial and final scope to transition between.
+	///
+	/// Fields of view are interpolated if both are of the same [`Fixed`] variant and switched at
+	/// half-time otherwise. Switching between orthographic and perspective projection mode
+	/// gradually narrows the field of view of the perspective scope while increasing the
+	/// distance between eye and target in such a way that the focus plane stays in scale.
+	pub const fn set_scopes(&mut self, old: Scope<N>, new: Scope<N>) {
+		self.scopes = Some((old, new));
+	}
+	/// Easing curve. Default is [`Ease::CubicInOut`].
+	#[must_use]
+	pub const fn ease(&self) -> Ease<N> {
+		self.ease
+	}
+	/// Sets easing curve. Default is [`Ease::CubicInOut`].
+	pub const fn set_ease(&mut self, ease: Ease<N>) {
+		self.ease = ease;
+	}
+	/// Zoom-out at half-time as ratio of distance between initial and final target.
+	///
+	/// Default is `0.0` for [`Self::new()`] and `1.0` for [`Self::fly_to()`].
+	#[must_use]
+	pub const fn fly(&self) -> N {
+		self.fly
+	}
+	/// Sets zoom-out at half-time as ratio of distance between initial and final target.
+	///
+	/// Default is `0.0` for [`Self::new()`] and `1.0` for [`Self::fly_to()`].
+	pub const fn set_fly(&mut self, fly: N) {
+		self.fly = fly;
+	}
+	/// Duration in seconds.
+	#[must_use]
+	pub const fn duration(&self) -> N {
+		self.dur
+	}
+	/// Whether the final frame has been computed.
+	#[must_use]
+	pub const fn finished(&self) -> bool {
+		self.time.is_none()
+	}
+	/// Computes frame and scope after tick of duration `dt` in seconds.
+	///
+	/// Returns `None` once the final frame and scope have been returned.
+	pub fn compute(&mut self, dt: N) -> Option<(Frame<N>, Option<Scope<N>>)> {
+		let time = self.time? + dt;
+		let t = if time < self.dur {
+			self.time = Some(time);
+			time / self.dur
+		} else {
+			self.time = None;
+			N::one()
+		};
+		Some(self.interpolate(t))
+	}
+	/// Frame and scope at linear time `t` between 0 and 1.
+	#[must_use]
+	pub fn interpolate(&self, t: N) -> (Frame<N>, Option<Scope<N>>) {
+		let half = convert(0.5);
+		let s = self.ease.ease(t);
+		let (old_frame, new_frame) = &self.frames;
+		let mut frame = old_frame.lerp_slerp(new_frame, s);
+		// Zoom out by parabola peaking at half-time and vanishing when overshooting.
+		let fly = self.fly * (new_frame.target() - old_frame.target()).norm();
+		let u = s.clamp(N::zero(), N::one());
+		let bump = convert::<_, N>(4.0) * u * (N::one() - u);
+		frame.set_distance(frame.distance() + fly * bump);
+		let scope = self.scopes.map(|(old_scope, new_scope)| {
+			let mut scope = if s < half { old_scope } else { new_scope };
+			let (old_znear, old_zfar) = old_scope.clip_planes(N::zero());
+			let (new_znear, new_zfar) = new_scope.clip_planes(N::zero());
+			if old_scope.scale() == new_scope.scale() {
+				// Clip plane distances measured from target have their near one negated.
+				let znear = old_znear + (new_znear - old_znear) * s;
+				let zfar = old_zfar + (new_zfar - old_zfar) * s;
+				scope.set_clip_planes(if scope.scale() { -znear } else { znear }, zfar);
+			}
+			let fov = match (old_scope.fov(), new_scope.fov()) {
+				(Fixed::Hor(old), Fixed::Hor(new)) => Fixed::Hor(old + (new - old) * s),
+				(Fixed::Ver(old), Fixed::Ver(new)) => Fixed::Ver(old + (new - old) * s),
+				(Fixed::Upp(old), Fixed::Upp(new)) => Fixed::Upp(old + (new - old) * s),
+				(old, new) => {
+					if s < half {
+						old
+					} else {
+						new
+					}
+				}
+			};
+			scope.set_fov(fov);
+			if old_scope.ortho() != new_scope.ortho() {
+				// Dolly ratio of perspective scope growing toward orthographic scope.
+				let max = convert::<_, N>(32.0);
+				let s = if old_scope.ortho() { N::one() - s } else { s };
+				let s = s.clamp(N::zero(), N::one());
+				let dolly = N::one() / (N::one() - s * (N::one() - max.recip()));
+				let ortho = s == N::one();
+				scope.set_ortho(ortho);
+				if !ortho {
+					scope.set_fov(narrow(scope.fov(), dolly));
+					frame.set_distance(frame.distance() * dolly);
+				}
+			}
+			scope
+		});
+		(frame, scope)
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Transition<M>
+	where
+		N: SubsetOf<M>,
+	{
+		let (old_frame, new_frame) = self.frames;
+		Transition {
+			frames: (old_frame.cast(), new_frame.cast()),
+			scopes: self
+				.scopes
+				.map(|(old_scope, new_scope)| (old_scope.cast(), new_scope.cast())),
+			ease: self.ease.cast(),
+			dur: self.dur.to_superset(),
+			time: self.time.map(|time| time.to_superset()),
+			fly: self.fly.to_superset(),
+		}
+	}
+}
+
+/// Narrows field of view by ratio of tangents of its half-angle.
+fn narrow<N: Copy + RealField>(fov: Fixed<N>, rat: N) -> Fixed<N> {
+	let two = N::one() + N::one();
+	match fov {
+		Fixed::Hor(fov) => Fixed::Hor(((fov / two).tan() / rat).atan() * two),
+		Fixed::Ver(fov) => Fixed::Ver(((fov / two).tan() / rat).atan() * two),
+		Fixed::Upp(upp) => Fixed::Upp(upp / rat),
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Transition<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Transition<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De>
+	for Transition<N>
+{
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<N: Copy + RealField> rkyv::Archive for Ease<N> {
+	type Archived = Self;
+	type Resolver = ();
+
+	#[inline]
+	#[allow(unsafe_code)]
+	unsafe fn resolve(&self, _: usize, (): Self::Resolver, out: *mut Self::Archived) {
+		unsafe {
+			out.write(rkyv::to_archived!(*self as Self));
+		}
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<Ser: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Serialize<Ser> for Ease<N> {
+	#[inline]
+	fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "rkyv")]
+impl<De: rkyv::Fallible + ?Sized, N: Copy + RealField> rkyv::Deserialize<Self, De> for Ease<N> {
+	#[inline]
+	fn deserialize(&self, _: &mut De) -> Result<Self, De::Error> {
+		Ok(rkyv::from_archived!(*self))
+	}
+}
@@ -0,0 +1,93 @@
+use crate::{Frame, Image};
+use nalgebra::{Point2, RealField, Unit, UnitQuaternion, Vector3};
+use simba::scalar::SubsetOf;
+
+/// Turntable orbit induced by displacement on screen.
+///
+/// Implements [`Default`] and can be created with `Turntable::default()`.
+///
+/// Horizontal displacements yaw around a fixed up axis in world space whereas vertical
+/// displacements pitch around [`Frame::pitch_axis()`] never introducing roll.
+///
+/// Both its methods must be invoked on matching events fired by your 3D graphics library of choice.
+#[derive(Debug, Clone)]
+pub struct Turntable<N: Copy + RealField> {
+	/// Caches previous cursor/finger position in camera space.
+	pos: Option<Point2<N>>,
+	/// Up axis in world space. Default is y-axis.
+	up: Unit<Vector3<N>>,
+}
+
+impl<N: Copy + RealField> Default for Turntable<N> {
+	fn default() -> Self {
+		Self {
+			pos: None,
+			up: Vector3::y_axis(),
+		}
+	}
+}
+
+impl<N: Copy + RealField> Turntable<N> {
+	/// Computes rotation in camera space of `frame` between previous and current cursor/finger
+	/// position.
+	///
+	/// Previous position is cached and has to be discarded on button/finger release via
+	/// [`Self::discard()`]. Current position `pos` is clamped between origin and maximum position
+	/// `max` as screen's width and height.
+	///
+	/// Carries cursor/finger displacements to arcs of the same length on circles with radius of
+	/// maximum of half the screen's width and height in compliance with [`crate::Orbit`]. Apply
+	/// the rotation via [`Frame::local_orbit()`] or [`crate::Delta::Orbit`].
+	///
+	/// Returns `None`:
+	///
+	///   * on first invocation and after [`Self::discard()`] as there is no previous position yet,
+	///   * in the unlikely case that a position event fires twice resulting in zero displacements.
+	pub fn compute(
+		&mut self,
+		pos: &Point2<N>,
+		max: &Point2<N>,
+		frame: &Frame<N>,
+	) -> Option<UnitQuaternion<N>> {
+		// Clamped cursor/finger position from left to right and top to bottom.
+		let pos = Image::clamp_pos_wrt_max(pos, max);
+		// Centered cursor/finger position and its maximum from left to right and bottom to top.
+		let (pos, max) = Image::transform_pos_and_max_wrt_max(&pos, max);
+		// Displacement vector from old to new position.
+		let vec = pos - self.pos.replace(pos)?;
+		if vec.x == N::zero() && vec.y == N::zero() {
+			return None;
+		}
+		// Treat maximum of half the screen's width or height as turntable's radius.
+		let max = max.x.max(max.y);
+		// Up axis in camera space.
+		let up = frame.view().rotation.inverse() * self.up;
+		let yaw = UnitQuaternion::from_axis_angle(&up, -vec.x / max);
+		let pitch = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), vec.y / max);
+		Some(yaw * pitch)
+	}
+	/// Discards cached previous cursor/finger position on button/finger release.
+	pub const fn discard(&mut self) {
+		self.pos = None;
+	}
+	/// Up axis in world space. Default is y-axis.
+	#[must_use]
+	pub const fn up_axis(&self) -> &Unit<Vector3<N>> {
+		&self.up
+	}
+	/// Sets up axis in world space. Default is y-axis.
+	pub const fn set_up_axis(&mut self, up: Unit<Vector3<N>>) {
+		self.up = up;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Turntable<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Turntable {
+			pos: self.pos.map(Point2::cast),
+			up: self.up.cast(),
+		}
+	}
+}
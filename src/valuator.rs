@@ -0,0 +1,56 @@
+use crate::Image;
+use nalgebra::{Point2, RealField, UnitQuaternion, Vector3};
+use simba::scalar::SubsetOf;
+
+/// Two-axis valuator orbit induced by displacement on screen.
+///
+/// Implements [`Default`] and can be created with `Valuator::default()`.
+///
+/// Horizontal and vertical displacements rotate around the y-axis and x-axis in camera space
+/// proportional to their lengths as opposed to the exponential map of [`crate::Orbit`].
+///
+/// Both its methods must be invoked on matching events fired by your 3D graphics library of choice.
+#[derive(Debug, Clone, Default)]
+pub struct Valuator<N: Copy + RealField> {
+	/// Caches previous cursor/finger position in camera space.
+	pos: Option<Point2<N>>,
+}
+
+impl<N: Copy + RealField> Valuator<N> {
+	/// Computes rotation between previous and current cursor/finger position.
+	///
+	/// Previous position is cached and has to be discarded on button/finger release via
+	/// [`Self::discard()`]. Current position `pos` is clamped between origin and maximum position
+	/// `max` as screen's width and height. Screen and camera space are defined as for
+	/// [`crate::Orbit::compute()`].
+	///
+	/// Returns `None`:
+	///
+	///   * on first invocation and after [`Self::discard()`] as there is no previous position yet,
+	///   * in the unlikely case that a position event fires twice resulting in zero displacements.
+	pub fn compute(&mut self, pos: &Point2<N>, max: &Point2<N>) -> Option<UnitQuaternion<N>> {
+		// Clamped cursor/finger position from left to right and top to bottom.
+		let pos = Image::clamp_pos_wrt_max(pos, max);
+		// Centered cursor/finger position and its maximum from left to right and bottom to top.
+		let (pos, max) = Image::transform_pos_and_max_wrt_max(&pos, max);
+		// Displacement vector from old to new position.
+		let vec = (pos - self.pos.replace(pos)?).push(N::zero());
+		// Treat maximum of half the screen's width or height as trackball's radius.
+		let vec = vec.cross(&Vector3::z()) / max.x.max(max.y);
+		(vec != Vector3::zeros()).then(|| UnitQuaternion::from_scaled_axis(vec))
+	}
+	/// Discards cached previous cursor/finger position on button/finger release.
+	pub const fn discard(&mut self) {
+		self.pos = None;
+	}
+	/// Casts components to another type, e.g., between [`f32`] and [`f64`].
+	#[must_use]
+	pub fn cast<M: Copy + RealField>(self) -> Valuator<M>
+	where
+		N: SubsetOf<M>,
+	{
+		Valuator {
+			pos: self.pos.map(Point2::cast),
+		}
+	}
+}